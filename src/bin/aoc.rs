@@ -0,0 +1,374 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+use aoc_2025_rust::answers::AnswerStore;
+use aoc_2025_rust::bench::{bench, compare, load_report, save_report, BenchResult};
+use aoc_2025_rust::days;
+use aoc_2025_rust::days::repeated_ids::{parse_base, parse_ranges, IdRule, IdStats};
+use aoc_2025_rust::input::InputSource;
+use aoc_2025_rust::logging::{init, LogConfig};
+use aoc_2025_rust::solution::{Answer, Solution};
+
+const USAGE: &str = "\
+Usage: aoc run <day> [<part>] [--input <path> | --example | --stdin] [--record]
+       aoc verify [<day>] [--example]
+       aoc bench [<day> [<part>]] [--runs <n>] [--example] [--output <csv>] [--compare <csv>] [--threshold <percent>]
+       aoc ids <rule> [--base <n>] [--input <path> | --example | --stdin]
+
+run     Runs the solution for the given day (and part, or all parts if omitted).
+        Input is read from `input/day_XX.txt` unless one of the following is given:
+          -i, --input <path>  read the given file (`-` for stdin)
+          -e, --example       read the example input `input/day_XX_example.txt`
+              --stdin         read standard input
+        With --record, the answers are stored in `input/answers.toml` as the correct ones
+        (only for the real or example input, also when given by its path).
+verify  Runs every solution (or those of the given day) with a recorded answer
+        for the real input, or the example input with --example,
+        and reports any answer that differs from the recorded one.
+bench   Runs every solution (or those of the given day and part) several times
+        and reports the min / median / max time spent parsing and solving.
+          -n, --runs <n>             number of runs per solution (default 10)
+          -e, --example              use the example input instead of the real one
+          -o, --output <csv>         save the timings as a CSV report
+          -c, --compare <csv>        compare with a previous report and fail on regressions
+          -t, --threshold <percent>  slowdown of the median allowed by --compare (default 10)
+ids     Sums the invalid IDs in the day 2 ID ranges under another rule than the puzzle's:
+          exactly:<n>   some block of digits repeated exactly n times (part 1 is exactly:2)
+          at-least:<n>  some block of digits repeated n or more times (part 2 is at-least:2)
+          palindrome    digits reading the same in both directions
+        Input options are the same as for run.
+          -b, --base <n>  read the digits of the IDs in base n (default 10)
+
+Diagnostic output of the solutions is off by default. Options for all commands:
+  -v, -vv, -vvv          show info, debug or trace messages
+  -q, --quiet            hide warnings and errors as well
+      --log <filters>    set the level for single days, e.g. `2=trace,11.2=debug`
+      --log-file <path>  write messages to a file instead of stderr";
+
+const DEFAULT_BENCH_RUNS: usize = 10;
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+const DEFAULT_ID_BASE: u32 = 10;
+
+struct RunArgs {
+    day: u8,
+    part: Option<u8>,
+    input: Option<InputSource>,
+    record: bool,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut positional: Vec<&String> = Vec::new();
+    let mut input: Option<InputSource> = None;
+    let mut example = false;
+    let mut record = false;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        let source = match arg.as_str() {
+            "--input" | "-i" => match args_iter.next().ok_or("missing path after --input")?.as_str() {
+                "-" => InputSource::Stdin,
+                path => InputSource::Path(PathBuf::from(path)),
+            },
+            "--example" | "-e" => {
+                example = true;
+                continue;
+            },
+            "--stdin" => InputSource::Stdin,
+            "--record" => {
+                record = true;
+                continue;
+            },
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+            _ => {
+                positional.push(arg);
+                continue;
+            },
+        };
+        if input.replace(source).is_some() {
+            return Err("only one input source can be given".to_string());
+        }
+    }
+    let day = positional.first().ok_or("missing day")?.parse::<u8>().map_err(|_| "invalid day")?;
+    let part = match positional.get(1) {
+        Some(p) => Some(p.parse::<u8>().map_err(|_| "invalid part")?),
+        None => None,
+    };
+    if positional.len() > 2 {
+        return Err("too many arguments".to_string());
+    }
+    let input = match (input, example) {
+        (Some(_), true) => return Err("only one input source can be given".to_string()),
+        (None, true) => Some(InputSource::Example(day)),
+        (input, false) => input,
+    };
+    Ok(RunArgs { day, part, input, record })
+}
+
+/// Solve a single solution, mapping failures to a printable message.
+fn solve(solution: &dyn Solution, input: &str) -> Result<Answer, String> {
+    solution.solve(input)
+        .map_err(|e| format!("error calculating result for day {} part {}: {e}", solution.day(), solution.part()))
+}
+
+/// Run a single solution, printing its answer and the time taken.
+fn run_solution(solution: &dyn Solution, input: &str) -> Result<Answer, String> {
+    let now = Instant::now();
+    let result = solve(solution, input)?;
+    let elapsed_time = now.elapsed();
+    println!("Day {} part {}", solution.day(), solution.part());
+    println!("{}: {result}", solution.output_message());
+    println!("Result calculated in {} ms", elapsed_time.as_millis());
+    Ok(result)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let run_args = parse_run_args(args)?;
+    let solutions: Vec<Box<dyn Solution>> = days::all()
+        .into_iter()
+        .filter(|s| s.day() == run_args.day && run_args.part.is_none_or(|part| s.part() == part))
+        .collect();
+    if solutions.is_empty() {
+        return Err(match run_args.part {
+            Some(part) => format!("no solution found for day {} part {part}", run_args.day),
+            None => format!("no solution found for day {}", run_args.day),
+        });
+    }
+    let source = run_args.input.unwrap_or(InputSource::Day(run_args.day));
+    let mut store = if run_args.record {
+        let label = source.canonical(run_args.day).label().ok_or("answers can only be recorded for the real or example input")?;
+        Some((AnswerStore::load_default().map_err(|e| e.to_string())?, label))
+    } else {
+        None
+    };
+    let input = source.read().map_err(|e| e.to_string())?;
+    for solution in &solutions {
+        let answer = run_solution(solution.as_ref(), &input)?;
+        if let Some((store, label)) = &mut store {
+            let (day, part) = (solution.day(), solution.part());
+            match store.record(day, part, label, &answer.to_string()).map_err(|e| e.to_string())? {
+                Some(previous) if previous != answer.to_string() => println!("Recorded answer for {label} (replacing {previous})"),
+                Some(_) => println!("Answer for {label} already recorded"),
+                None => println!("Recorded answer for {label}"),
+            }
+        }
+    }
+    if let Some((store, _)) = store {
+        store.save().map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+struct VerifyArgs {
+    day: Option<u8>,
+    example: bool,
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyArgs, String> {
+    let mut day = None;
+    let mut example = false;
+    for arg in args {
+        match arg.as_str() {
+            "--example" | "-e" => example = true,
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+            _ if day.is_none() => day = Some(arg.parse::<u8>().map_err(|_| "invalid day")?),
+            _ => return Err("too many arguments".to_string()),
+        }
+    }
+    Ok(VerifyArgs { day, example })
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let verify_args = parse_verify_args(args)?;
+    let store = AnswerStore::load_default().map_err(|e| e.to_string())?;
+    let mut n_checked = 0;
+    let mut failures: Vec<String> = Vec::new();
+    for solution in days::all().into_iter().filter(|s| verify_args.day.is_none_or(|day| s.day() == day)) {
+        let (day, part) = (solution.day(), solution.part());
+        let source = if verify_args.example { InputSource::Example(day) } else { InputSource::Day(day) };
+        let label = source.label().expect("day and example inputs always have a label");
+        let Some(expected) = store.get(day, part, &label) else {
+            println!("Day {day} part {part}: no recorded answer for {label}");
+            continue;
+        };
+        n_checked += 1;
+        let result = source.read().map_err(|e| e.to_string()).and_then(|input| solve(solution.as_ref(), &input));
+        match result {
+            Ok(answer) if answer.to_string() == expected => println!("Day {day} part {part}: ok"),
+            Ok(answer) => {
+                println!("Day {day} part {part}: WRONG - expected {expected}, got {answer}");
+                failures.push(format!("day {day} part {part}"));
+            },
+            Err(e) => {
+                println!("Day {day} part {part}: FAILED - {e}");
+                failures.push(format!("day {day} part {part}"));
+            },
+        }
+    }
+    if failures.is_empty() {
+        println!("{n_checked} recorded answers verified");
+        Ok(())
+    } else {
+        Err(format!("{} of {n_checked} recorded answers not reproduced: {}", failures.len(), failures.join(", ")))
+    }
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    runs: usize,
+    example: bool,
+    output: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        part: None,
+        runs: DEFAULT_BENCH_RUNS,
+        example: false,
+        output: None,
+        compare: None,
+        threshold: DEFAULT_REGRESSION_THRESHOLD,
+    };
+    let mut positional: Vec<&String> = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        let mut value = || args_iter.next().ok_or(format!("missing value after {arg}"));
+        match arg.as_str() {
+            "--runs" | "-n" => bench_args.runs = value()?.parse().map_err(|_| "invalid number of runs")?,
+            "--example" | "-e" => bench_args.example = true,
+            "--output" | "-o" => bench_args.output = Some(PathBuf::from(value()?)),
+            "--compare" | "-c" => bench_args.compare = Some(PathBuf::from(value()?)),
+            "--threshold" | "-t" => bench_args.threshold = value()?.parse().map_err(|_| "invalid threshold")?,
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+            _ => positional.push(arg),
+        }
+    }
+    if positional.len() > 2 {
+        return Err("too many arguments".to_string());
+    }
+    bench_args.day = positional.first().map(|d| d.parse::<u8>().map_err(|_| "invalid day")).transpose()?;
+    bench_args.part = positional.get(1).map(|p| p.parse::<u8>().map_err(|_| "invalid part")).transpose()?;
+    Ok(bench_args)
+}
+
+fn run_bench(args: &[String]) -> Result<(), String> {
+    let bench_args = parse_bench_args(args)?;
+    let previous = bench_args.compare.as_deref().map(load_report).transpose().map_err(|e| e.to_string())?;
+    let solutions: Vec<Box<dyn Solution>> = days::all()
+        .into_iter()
+        .filter(|s| bench_args.day.is_none_or(|day| s.day() == day) && bench_args.part.is_none_or(|part| s.part() == part))
+        .collect();
+    let mut results: Vec<BenchResult> = Vec::new();
+    for solution in &solutions {
+        let (day, part) = (solution.day(), solution.part());
+        let source = if bench_args.example { InputSource::Example(day) } else { InputSource::Day(day) };
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {day} part {part}: skipped - {e}");
+                continue;
+            },
+        };
+        let (_, result) = bench(solution.as_ref(), &input, bench_args.runs)
+            .map_err(|e| format!("error calculating result for day {day} part {part}: {e}"))?;
+        results.push(result);
+    }
+    println!("\n{:<16} {:>40} {:>40}", "min/median/max", "parse", "solve");
+    for result in &results {
+        println!("Day {:>2} part {}   {:>40} {:>40}", result.day, result.part, result.parse.to_string(), result.solve.to_string());
+    }
+    if let Some(output) = &bench_args.output {
+        save_report(output, &results).map_err(|e| e.to_string())?;
+        println!("Report saved to {}", output.display());
+    }
+    if let Some(previous) = previous {
+        let regressions = compare(&previous, &results, bench_args.threshold / 100.0);
+        if !regressions.is_empty() {
+            let lines: Vec<String> = regressions.iter().map(|r| r.to_string()).collect();
+            return Err(format!("{} solutions slower than in the previous report:\n{}", regressions.len(), lines.join("\n")));
+        }
+        println!("No regressions compared to the previous report");
+    }
+    Ok(())
+}
+
+struct IdsArgs {
+    rule: IdRule,
+    base: u32,
+    input: InputSource,
+}
+
+fn parse_ids_args(args: &[String]) -> Result<IdsArgs, String> {
+    let mut rule = None;
+    let mut base = DEFAULT_ID_BASE;
+    let mut input: Option<InputSource> = None;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        let mut value = || args_iter.next().ok_or(format!("missing value after {arg}"));
+        let source = match arg.as_str() {
+            "--base" | "-b" => {
+                base = parse_base(value()?).map_err(|e| e.to_string())?;
+                continue;
+            },
+            "--input" | "-i" => match value()?.as_str() {
+                "-" => InputSource::Stdin,
+                path => InputSource::Path(PathBuf::from(path)),
+            },
+            "--example" | "-e" => InputSource::Example(2),
+            "--stdin" => InputSource::Stdin,
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+            _ if rule.is_none() => {
+                rule = Some(arg.parse::<IdRule>().map_err(|e| e.to_string())?);
+                continue;
+            },
+            _ => return Err("too many arguments".to_string()),
+        };
+        if input.replace(source).is_some() {
+            return Err("only one input source can be given".to_string());
+        }
+    }
+    let rule = rule.ok_or("missing rule")?;
+    Ok(IdsArgs { rule, base, input: input.unwrap_or(InputSource::Day(2)) })
+}
+
+fn ids(args: &[String]) -> Result<(), String> {
+    let ids_args = parse_ids_args(args)?;
+    let input = ids_args.input.read().map_err(|e| e.to_string())?;
+    let stats: IdStats = parse_ranges(&input).into_iter().map(|range| ids_args.rule.stats(range, ids_args.base)).sum();
+    println!("Invalid IDs under rule {} in base {}: {}", ids_args.rule, ids_args.base, stats.count);
+    println!("Total: {}", stats.sum);
+    Ok(())
+}
+
+fn run_command(args: Vec<String>) -> Result<(), String> {
+    let (log_config, args) = LogConfig::from_args(args).map_err(|e| e.to_string())?;
+    init(log_config).map_err(|e| e.to_string())?;
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => run_bench(&args[1..]),
+        Some("ids") => ids(&args[1..]),
+        Some("help" | "--help" | "-h") => {
+            println!("{USAGE}");
+            Ok(())
+        },
+        Some(command) => Err(format!("unknown command: {command}\n\n{USAGE}")),
+        None => Err(format!("no command given\n\n{USAGE}")),
+    };
+    log::logger().flush();
+    result
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run_command(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        },
+    }
+}
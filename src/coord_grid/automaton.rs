@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use super::{GridLike, GridLikeMut};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use super::ParallelGridLike;
+
+/// How a rule is applied to the cells of a grid in each generation.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Every cell is computed from the previous generation.
+    #[default]
+    Synchronous,
+    /// Cells are updated one by one in row-major order, so later cells see earlier updates.
+    InPlace,
+}
+
+/// Reason `Automaton::run` stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// Applying the rule to generation `generation` changes nothing.
+    Fixpoint { generation: usize },
+    /// Generation `start + length` is the same as generation `start`.
+    Cycle { start: usize, length: usize },
+    /// The maximum number of generations was reached.
+    GenerationLimit,
+}
+
+/// Cell values of a state seen during a run, with the generation it was first seen in.
+type SeenState<T> = (Vec<T>, usize);
+
+/// Cellular automaton driver: repeatedly applies `rule` to every cell of a grid.
+/// `rule` returns the new value of the cell at the given coordinates.
+pub struct Automaton<G: GridLike, F> {
+    grid: G,
+    rule: F,
+    mode: UpdateMode,
+    generation: usize,
+    change_counts: Vec<usize>,
+    seen_states: HashMap<u64, Vec<SeenState<G::Value>>>,
+}
+
+impl<G, F> Automaton<G, F>
+where
+    G: GridLikeMut + Clone,
+    G::Value: Hash,
+    F: Fn(&G, (usize, usize)) -> G::Value,
+{
+    pub fn new(grid: G, rule: F) -> Self {
+        let mut automaton = Self { grid, rule, mode: UpdateMode::default(), generation: 0, change_counts: Vec::new(), seen_states: HashMap::new() };
+        automaton.record_state();
+        automaton
+    }
+
+    pub fn mode(mut self, mode: UpdateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> G {
+        self.grid
+    }
+
+    /// Number of generations computed so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Number of cells changed in each generation (`change_counts()[k]` for generation `k + 1`).
+    pub fn change_counts(&self) -> &[usize] {
+        &self.change_counts
+    }
+
+    /// Compute the next generation and return the number of cells that changed.
+    pub fn step(&mut self) -> usize {
+        let mut changes = 0;
+        match self.mode {
+            UpdateMode::Synchronous => {
+                let mut next = self.grid.clone();
+                for (coords, v) in self.grid.iter_values_with_coords() {
+                    let new_v = (self.rule)(&self.grid, coords);
+                    if new_v != v {
+                        next.set(coords, new_v).unwrap();
+                        changes += 1;
+                    }
+                }
+                self.grid = next;
+            },
+            UpdateMode::InPlace => {
+                let coords: Vec<(usize, usize)> = self.grid.iter_coords().collect();
+                for coords in coords {
+                    let new_v = (self.rule)(&self.grid, coords);
+                    if new_v != self.grid.get(coords).unwrap() {
+                        self.grid.set(coords, new_v).unwrap();
+                        changes += 1;
+                    }
+                }
+            },
+        }
+        self.generation += 1;
+        self.change_counts.push(changes);
+        changes
+    }
+
+    /// Step until a fixpoint or cycle is found, or until `max_generations` generations have been computed in total.
+    /// Cycles are detected by comparing with the grid states seen during `run` (and the initial state),
+    /// which are looked up by hash.
+    pub fn run(&mut self, max_generations: Option<usize>) -> RunOutcome {
+        self.run_with(max_generations, Self::step)
+    }
+
+    fn run_with(&mut self, max_generations: Option<usize>, step: impl Fn(&mut Self) -> usize) -> RunOutcome {
+        while max_generations.is_none_or(|max| self.generation < max) {
+            if step(self) == 0 {
+                self.generation -= 1;
+                self.change_counts.pop();
+                return RunOutcome::Fixpoint { generation: self.generation };
+            }
+            if let Some(start) = self.record_state() {
+                return RunOutcome::Cycle { start, length: self.generation - start };
+            }
+        }
+        RunOutcome::GenerationLimit
+    }
+
+    /// Remember the current state, returning the generation it was first seen in if it was seen before.
+    fn record_state(&mut self) -> Option<usize> {
+        let values: Vec<G::Value> = self.grid.iter_values_with_coords().map(|(_, v)| v).collect();
+        let states = self.seen_states.entry(state_hash(&self.grid)).or_default();
+        if let Some((_, generation)) = states.iter().find(|(state, _)| *state == values) {
+            return Some(*generation);
+        }
+        states.push((values, self.generation));
+        None
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<G, F> Automaton<G, F>
+where
+    G: GridLikeMut + Clone + Sync,
+    G::Value: Hash + Send + Sync,
+    F: Fn(&G, (usize, usize)) -> G::Value + Sync,
+{
+    /// Like `step`, but computes the cells of a synchronous update in parallel.
+    /// In-place updates depend on the order of the cells and are always sequential.
+    pub fn par_step(&mut self) -> usize {
+        if self.mode == UpdateMode::InPlace {
+            return self.step();
+        }
+        let new_values: Vec<((usize, usize), G::Value)> = self.grid.par_iter_coords()
+            .map(|coords| (coords, (self.rule)(&self.grid, coords)))
+            .collect();
+        let mut changes = 0;
+        for (coords, new_v) in new_values {
+            if new_v != self.grid.get(coords).unwrap() {
+                self.grid.set(coords, new_v).unwrap();
+                changes += 1;
+            }
+        }
+        self.generation += 1;
+        self.change_counts.push(changes);
+        changes
+    }
+
+    /// Like `run`, but using `par_step`.
+    pub fn par_run(&mut self, max_generations: Option<usize>) -> RunOutcome {
+        self.run_with(max_generations, Self::par_step)
+    }
+}
+
+fn state_hash<G: GridLike>(grid: &G) -> u64 where G::Value: Hash {
+    let mut hasher = DefaultHasher::new();
+    grid.shape().hash(&mut hasher);
+    grid.iter_values_with_coords().for_each(|(_, v)| v.hash(&mut hasher));
+    hasher.finish()
+}
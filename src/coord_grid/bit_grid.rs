@@ -0,0 +1,194 @@
+use std::ops::{BitAndAssign, BitOrAssign};
+use super::{DynGrid, Grid, GridError, GridLike, GridLikeMut, Vec2};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Boolean grid storing each row as packed 64-bit words,
+/// so that unions, intersections and collision tests work on whole words.
+/// Unused bits at the end of each row are always zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    height: usize,
+    width: usize,
+    words_per_row: usize,
+}
+
+/// Call `f(word_idx, word)` for each non-zero word of `row` shifted by `shift` bits towards higher column indices.
+fn for_each_shifted_word(row: &[u64], shift: usize, mut f: impl FnMut(usize, u64)) {
+    let (word_shift, bit_shift) = (shift / WORD_BITS, shift % WORD_BITS);
+    for (k, &word) in row.iter().enumerate() {
+        if word == 0 { continue; }
+        f(k + word_shift, word << bit_shift);
+        if bit_shift > 0 && word >> (WORD_BITS - bit_shift) != 0 {
+            f(k + word_shift + 1, word >> (WORD_BITS - bit_shift));
+        }
+    }
+}
+
+impl BitGrid {
+    /// Create a grid with the given `(height, width)`, with all cells `false`.
+    pub fn create(shape: (usize, usize)) -> Self {
+        let (height, width) = shape;
+        let words_per_row = width.div_ceil(WORD_BITS);
+        Self { words: vec![0; height * words_per_row], height, width, words_per_row }
+    }
+
+    /// Copy any boolean grid.
+    pub fn from_grid<G: GridLike<Value=bool>>(grid: &G) -> Self {
+        let mut bit_grid = Self::create(grid.shape());
+        grid.iter_values_with_coords().filter(|(_, v)| *v).for_each(|(coords, _)| bit_grid.set(coords, true).unwrap());
+        bit_grid
+    }
+
+    fn bit(&self, coords: (usize, usize)) -> (usize, u64) {
+        let (i, j) = coords;
+        (i * self.words_per_row + j / WORD_BITS, 1 << (j % WORD_BITS))
+    }
+
+    /// Return the packed words of row `i` (bit `j % 64` of word `j / 64` is column `j`).
+    pub fn row_words(&self, i: usize) -> &[u64] {
+        &self.words[(i * self.words_per_row)..((i + 1) * self.words_per_row)]
+    }
+
+    /// Number of `true` cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Whether any cell is `true`.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|word| *word != 0)
+    }
+
+    /// Clear the unused bits at the end of every row.
+    fn mask_rows(&mut self) {
+        if self.width.is_multiple_of(WORD_BITS) { return; }
+        let mask = (1u64 << (self.width % WORD_BITS)) - 1;
+        for i in 0..self.height {
+            self.words[(i + 1) * self.words_per_row - 1] &= mask;
+        }
+    }
+
+    fn check_fits(&self, other: &Self, offset: (usize, usize)) -> Result<(), GridError> {
+        let (i0, j0) = offset;
+        let (height, width) = other.shape();
+        if i0 + height <= self.height && j0 + width <= self.width {
+            Ok(())
+        } else {
+            Err(GridError::out_of_bounds(((i0 + height).saturating_sub(1), (j0 + width).saturating_sub(1)), self.shape()))
+        }
+    }
+
+    /// Whether `other`, placed with its top-left corner at `offset`, has any `true` cell in common with `self`.
+    pub fn overlaps(&self, other: &Self, offset: (usize, usize)) -> Result<bool, GridError> {
+        self.check_fits(other, offset)?;
+        let (i0, j0) = offset;
+        Ok((0..other.height).any(|i| {
+            let row = &self.words[((i0 + i) * self.words_per_row)..((i0 + i + 1) * self.words_per_row)];
+            let mut overlap = false;
+            for_each_shifted_word(other.row_words(i), j0, |k, word| overlap |= row[k] & word != 0);
+            overlap
+        }))
+    }
+
+    /// Set every cell that is `true` in `other`, placed with its top-left corner at `offset`.
+    pub fn union_at(&mut self, other: &Self, offset: (usize, usize)) -> Result<(), GridError> {
+        self.check_fits(other, offset)?;
+        let (i0, j0) = offset;
+        for i in 0..other.height {
+            let row_start = (i0 + i) * self.words_per_row;
+            for_each_shifted_word(other.row_words(i), j0, |k, word| self.words[row_start + k] |= word);
+        }
+        Ok(())
+    }
+
+    /// Return a copy of `self` with every cell moved by `offset`; cells moved outside the grid are lost.
+    pub fn shifted(&self, offset: Vec2) -> Self {
+        let mut new_grid = Self::create(self.shape());
+        for i in 0..self.height {
+            let Some(new_i) = i.checked_add_signed(offset.i).filter(|new_i| *new_i < self.height) else { continue };
+            let row = self.row_words(i);
+            let new_row_start = new_i * self.words_per_row;
+            let new_row = &mut new_grid.words[new_row_start..(new_row_start + self.words_per_row)];
+            if offset.j >= 0 {
+                for_each_shifted_word(row, offset.j as usize, |k, word| {
+                    if k < new_row.len() { new_row[k] |= word; }
+                });
+            } else {
+                let shift = offset.j.unsigned_abs();
+                let (word_shift, bit_shift) = (shift / WORD_BITS, shift % WORD_BITS);
+                for (k, &word) in row.iter().enumerate().skip(word_shift) {
+                    new_row[k - word_shift] |= word >> bit_shift;
+                    if bit_shift > 0 && k > word_shift {
+                        new_row[k - word_shift - 1] |= word << (WORD_BITS - bit_shift);
+                    }
+                }
+            }
+        }
+        new_grid.mask_rows();
+        new_grid
+    }
+}
+
+impl GridLike for BitGrid {
+    type Value = bool;
+
+    fn shape(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    fn get(&self, coords: (usize, usize)) -> Result<bool, GridError> {
+        if self.contains_coords(coords) {
+            let (word_idx, mask) = self.bit(coords);
+            Ok(self.words[word_idx] & mask != 0)
+        } else {
+            Err(GridError::out_of_bounds(coords, self.shape()))
+        }
+    }
+}
+
+impl GridLikeMut for BitGrid {
+    fn set(&mut self, coords: (usize, usize), v: bool) -> Result<(), GridError> {
+        if self.contains_coords(coords) {
+            let (word_idx, mask) = self.bit(coords);
+            if v { self.words[word_idx] |= mask; } else { self.words[word_idx] &= !mask; }
+            Ok(())
+        } else {
+            Err(GridError::out_of_bounds(coords, self.shape()))
+        }
+    }
+
+    fn set_all(&mut self, v: bool) {
+        self.words.fill(if v { u64::MAX } else { 0 });
+        self.mask_rows();
+    }
+}
+
+/// Panics if the shapes differ.
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        assert_eq!(self.shape(), other.shape(), "cannot combine grids of different shapes");
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other_word)| *word |= other_word);
+    }
+}
+
+/// Panics if the shapes differ.
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        assert_eq!(self.shape(), other.shape(), "cannot combine grids of different shapes");
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other_word)| *word &= other_word);
+    }
+}
+
+impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize> From<&Grid<GRID_WIDTH, GRID_HEIGHT, bool>> for BitGrid {
+    fn from(grid: &Grid<GRID_WIDTH, GRID_HEIGHT, bool>) -> Self {
+        Self::from_grid(grid)
+    }
+}
+
+impl From<&DynGrid<bool>> for BitGrid {
+    fn from(grid: &DynGrid<bool>) -> Self {
+        Self::from_grid(grid)
+    }
+}
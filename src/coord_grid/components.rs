@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+use super::{DynGrid, GridLike, GridLikeMut, Neighbourhood};
+
+/// Summary of one connected component found by `GridLike::label_components` or `GridLike::label_regions`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    /// Label of the component in `Components::labels` (starting at 1)
+    pub label: usize,
+    /// Number of cells
+    pub size: usize,
+    /// Top-left and bottom-right corners (both inclusive)
+    pub bounding_box: ((usize, usize), (usize, usize)),
+    /// Number of cell edges bordering cells outside the component (or the edge of the grid).
+    /// Only the 4 edges of each cell count, whatever the neighbourhood used for labelling,
+    /// so two diagonally connected cells have a perimeter of 8.
+    pub perimeter: usize,
+}
+
+/// Connected-component labelling of a grid.
+#[derive(Debug, Clone)]
+pub struct Components {
+    /// Component label of every cell, `0` for cells outside all components
+    pub labels: DynGrid<usize>,
+    /// Components ordered by label (`components[k]` has label `k + 1`)
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    pub fn get(&self, label: usize) -> Option<&Component> {
+        label.checked_sub(1).and_then(|idx| self.components.get(idx))
+    }
+
+    /// Return the component containing `coords`, if any.
+    pub fn component_at(&self, coords: (usize, usize)) -> Option<&Component> {
+        self.get(self.labels.get(coords).ok()?)
+    }
+}
+
+/// Collect the cells reachable from `start` via cells that satisfy `predicate`.
+pub(super) fn flood_fill<G: GridLike>(grid: &G, start: (usize, usize), predicate: impl Fn(G::Value) -> bool, neighbourhood: Neighbourhood) -> Vec<(usize, usize)> {
+    match grid.get(start) {
+        Ok(v) if predicate(v) => (),
+        _ => return Vec::new(),
+    }
+    let mut visited: DynGrid<bool> = DynGrid::create(grid.shape());
+    visited.set(start, true).unwrap();
+    let mut cells = vec![start];
+    let mut idx = 0;
+    while idx < cells.len() {
+        let coords = cells[idx];
+        for direction in neighbourhood.directions() {
+            let Ok(next) = grid.move_coords(coords, direction) else { continue };
+            if !visited.get(next).unwrap() && predicate(grid.get(next).unwrap()) {
+                visited.set(next, true).unwrap();
+                cells.push(next);
+            }
+        }
+        idx += 1;
+    }
+    cells
+}
+
+/// Label the connected regions of cells that satisfy `predicate`, with the same connectivity as `flood_fill`.
+pub(super) fn label_components<G: GridLike>(grid: &G, predicate: impl Fn(G::Value) -> bool, neighbourhood: Neighbourhood) -> Components {
+    label_connected(grid, predicate, |_, _| true, neighbourhood)
+}
+
+/// Label the connected regions of equal-valued cells that satisfy `predicate`.
+pub(super) fn label_regions<G: GridLike>(grid: &G, predicate: impl Fn(G::Value) -> bool, neighbourhood: Neighbourhood) -> Components {
+    label_connected(grid, predicate, |a, b| a == b, neighbourhood)
+}
+
+/// Label the connected regions of cells that satisfy `predicate`,
+/// where adjacent cells with values `a` and `b` only connect if `connects(a, b)`.
+fn label_connected<G: GridLike>(grid: &G, predicate: impl Fn(G::Value) -> bool, connects: impl Fn(G::Value, G::Value) -> bool, neighbourhood: Neighbourhood) -> Components {
+    let mut labels: DynGrid<usize> = DynGrid::create(grid.shape());
+    let mut components = Vec::new();
+    for (start, start_value) in grid.iter_values_with_coords() {
+        if labels.get(start).unwrap() != 0 || !predicate(start_value) { continue; }
+        let label = components.len() + 1;
+        labels.set(start, label).unwrap();
+        let mut queue = VecDeque::from([start]);
+        let mut component = Component { label, size: 0, bounding_box: (start, start), perimeter: 0 };
+        while let Some(coords) = queue.pop_front() {
+            let value = grid.get(coords).unwrap();
+            component.size += 1;
+            let ((min_i, min_j), (max_i, max_j)) = component.bounding_box;
+            component.bounding_box = ((min_i.min(coords.0), min_j.min(coords.1)), (max_i.max(coords.0), max_j.max(coords.1)));
+            for direction in neighbourhood.directions() {
+                let Ok(next) = grid.move_coords(coords, direction) else { continue };
+                let next_value = grid.get(next).unwrap();
+                if labels.get(next).unwrap() == 0 && predicate(next_value) && connects(value, next_value) {
+                    labels.set(next, label).unwrap();
+                    queue.push_back(next);
+                }
+            }
+        }
+        components.push(component);
+    }
+    // perimeters are only counted once all labels are known, always along the 4 edges of each cell
+    for (coords, label) in labels.iter_values_with_coords() {
+        if label == 0 { continue; }
+        components[label - 1].perimeter += Neighbourhood::VonNeumann.directions().into_iter().filter(|direction| {
+            !labels.move_coords(coords, *direction).is_ok_and(|next| labels.get(next).unwrap() == label)
+        }).count();
+    }
+    Components { labels, components }
+}
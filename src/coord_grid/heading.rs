@@ -0,0 +1,41 @@
+use super::{Direction, Point, Turn};
+
+/// Position and facing of a walker moving across a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+impl Heading {
+    pub fn new(position: Point, direction: Direction) -> Self {
+        Self { position, direction }
+    }
+
+    /// Return the position one step ahead.
+    pub fn ahead(&self) -> Point {
+        self.position.step(self.direction)
+    }
+
+    /// Move one step forward, returning the new position.
+    pub fn step(&mut self) -> Point {
+        self.position = self.ahead();
+        self.position
+    }
+
+    /// Move `n` steps forward (backward for negative `n`).
+    pub fn step_by(&mut self, n: isize) -> Point {
+        self.position += self.direction.offset() * n;
+        self.position
+    }
+
+    /// Turn on the spot.
+    pub fn turn(&mut self, turn: Turn) {
+        self.direction = self.direction.turn(turn);
+    }
+
+    /// Return a copy of `self` turned on the spot.
+    pub fn turned(&self, turn: Turn) -> Self {
+        Self { position: self.position, direction: self.direction.turn(turn) }
+    }
+}
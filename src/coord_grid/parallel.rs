@@ -0,0 +1,25 @@
+use std::fmt::Debug;
+use rayon::prelude::*;
+use super::{DynGrid, GridLike};
+
+/// Parallel versions of whole-grid operations (requires the `parallel` feature).
+/// Implemented for every grid that can be shared between threads.
+pub trait ParallelGridLike: GridLike + Sync where Self::Value: Send + Sync {
+    fn par_iter_coords(&self) -> impl ParallelIterator<Item=(usize, usize)> {
+        let (height, width) = self.shape();
+        (0..height).into_par_iter().flat_map_iter(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    fn par_iter_values_with_coords(&self) -> impl ParallelIterator<Item=((usize, usize), Self::Value)> {
+        self.par_iter_coords().map(move |coords| (coords, self.get(coords).unwrap()))
+    }
+
+    /// Build a new grid of the same shape by applying `f` to every cell and its value.
+    fn par_map<U: Copy + Debug + Default + PartialEq + Send>(&self, f: impl Fn((usize, usize), Self::Value) -> U + Sync + Send) -> DynGrid<U> {
+        let (height, width) = self.shape();
+        let values: Vec<U> = self.par_iter_values_with_coords().map(|(coords, v)| f(coords, v)).collect();
+        DynGrid { _grid: values, _height: height, _width: width }
+    }
+}
+
+impl<G: GridLike + Sync> ParallelGridLike for G where G::Value: Send + Sync {}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use super::Direction;
+
+/// Signed, unbounded 2D coordinates in the same `(i, j)` convention as grids
+/// (`i` increases southwards, `j` eastwards).
+/// Also used for offsets between points (see `Vec2`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub i: isize,
+    pub j: isize,
+}
+
+/// Offset between two `Point`s.
+pub type Vec2 = Point;
+
+impl Point {
+    pub const ORIGIN: Self = Self { i: 0, j: 0 };
+
+    pub fn new(i: isize, j: isize) -> Self {
+        Self { i, j }
+    }
+
+    /// Return the point one step away in `direction`.
+    pub fn step(&self, direction: Direction) -> Self {
+        *self + direction.offset()
+    }
+
+    /// Return the points one step away in each of the given directions.
+    pub fn neighbours<D: IntoIterator<Item=Direction>>(&self, directions: D) -> impl Iterator<Item=(Direction, Self)> + use<D> {
+        let point = *self;
+        directions.into_iter().map(move |direction| (direction, point.step(direction)))
+    }
+
+    /// Sum of the absolute coordinate differences.
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.i.abs_diff(other.i) + self.j.abs_diff(other.j)
+    }
+
+    /// Largest absolute coordinate difference (number of king's moves).
+    pub fn chebyshev_distance(&self, other: &Self) -> usize {
+        self.i.abs_diff(other.i).max(self.j.abs_diff(other.j))
+    }
+
+    /// Component-wise minimum.
+    pub fn componentwise_min(&self, other: &Self) -> Self {
+        Self::new(self.i.min(other.i), self.j.min(other.j))
+    }
+
+    /// Component-wise maximum.
+    pub fn componentwise_max(&self, other: &Self) -> Self {
+        Self::new(self.i.max(other.i), self.j.max(other.j))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.i, self.j)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.i + other.i, self.j + other.j)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.i - other.i, self.j - other.j)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.i, -self.j)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::new(self.i * factor, self.j * factor)
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((i, j): (isize, isize)) -> Self {
+        Self::new(i, j)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(point: Point) -> Self {
+        (point.i, point.j)
+    }
+}
+
+/// Panics for coordinates larger than `isize::MAX`, which no grid can reach.
+impl From<(usize, usize)> for Point {
+    fn from((i, j): (usize, usize)) -> Self {
+        Self::new(i.try_into().unwrap(), j.try_into().unwrap())
+    }
+}
+
+impl TryFrom<Point> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok((point.i.try_into()?, point.j.try_into()?))
+    }
+}
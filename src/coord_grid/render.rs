@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::io::IsTerminal;
+use super::{GridCell, GridLike};
+
+/// Frame drawn around a rendered grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Border {
+    None,
+    /// `-` above and below, `|` on either side
+    #[default]
+    Ascii,
+    /// Box-drawing characters (`┌─┐│└┘`)
+    Box,
+}
+
+impl Border {
+    /// Return the `(top-left, top-right, bottom-left, bottom-right, horizontal, vertical)` characters.
+    fn chars(&self) -> Option<(char, char, char, char, char, char)> {
+        match self {
+            Border::None => None,
+            Border::Ascii => Some(('-', '-', '-', '-', '-', '|')),
+            Border::Box => Some(('┌', '┐', '└', '┘', '─', '│')),
+        }
+    }
+}
+
+/// ANSI terminal colour used for overlays and highlights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn ansi_code(&self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+/// Whether colours are written as ANSI escape codes.
+/// A renderer does not know where its output ends up, so the terminal checks name the stream they look at.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColourMode {
+    /// Only use colours if stdout is a terminal, for renderings printed to stdout.
+    #[default]
+    IfStdoutTerminal,
+    /// Only use colours if stderr is a terminal, for renderings printed to stderr.
+    IfStderrTerminal,
+    Always,
+    Never,
+}
+
+impl ColourMode {
+    fn enabled(&self) -> bool {
+        match self {
+            ColourMode::IfStdoutTerminal => io::stdout().is_terminal(),
+            ColourMode::IfStderrTerminal => io::stderr().is_terminal(),
+            ColourMode::Always => true,
+            ColourMode::Never => false,
+        }
+    }
+}
+
+enum LayerChar {
+    Keep,
+    Replace(char),
+}
+
+/// Additional information drawn on top of the base grid.
+struct Layer<'a> {
+    mark: Box<dyn Fn((usize, usize)) -> Option<LayerChar> + 'a>,
+    colour: Option<Colour>,
+}
+
+/// Text rendering of a grid, with an optional border
+/// and any number of overlays drawn on top of the cells (later overlays take precedence).
+pub struct GridRenderer<'a, G: GridLike> {
+    grid: &'a G,
+    cell_char: Box<dyn Fn(G::Value) -> char + 'a>,
+    border: Border,
+    colour_mode: ColourMode,
+    layers: Vec<Layer<'a>>,
+}
+
+impl<'a, G: GridLike> GridRenderer<'a, G> {
+    /// Render `grid`, mapping every cell value to a character with `cell_char`.
+    pub fn new(grid: &'a G, cell_char: impl Fn(G::Value) -> char + 'a) -> Self {
+        Self {
+            grid,
+            cell_char: Box::new(cell_char),
+            border: Border::default(),
+            colour_mode: ColourMode::default(),
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn colour_mode(mut self, colour_mode: ColourMode) -> Self {
+        self.colour_mode = colour_mode;
+        self
+    }
+
+    /// Draw a second grid on top of the base grid.
+    /// Cells for which `cell_char` returns `None` (or which lie outside `other`) show the cells below.
+    pub fn overlay_grid<O: GridLike>(mut self, other: &'a O, cell_char: impl Fn(O::Value) -> Option<char> + 'a, colour: Option<Colour>) -> Self {
+        self.layers.push(Layer {
+            mark: Box::new(move |coords| other.get(coords).ok().and_then(&cell_char).map(LayerChar::Replace)),
+            colour,
+        });
+        self
+    }
+
+    /// Draw `c` at each of the given coordinates.
+    pub fn overlay_coords(mut self, coords: impl IntoIterator<Item=(usize, usize)>, c: char, colour: Option<Colour>) -> Self {
+        let coords: HashSet<(usize, usize)> = coords.into_iter().collect();
+        self.layers.push(Layer {
+            mark: Box::new(move |cell_coords| coords.contains(&cell_coords).then_some(LayerChar::Replace(c))),
+            colour,
+        });
+        self
+    }
+
+    /// Colour the cells at the given coordinates, keeping their characters.
+    pub fn highlight(mut self, coords: impl IntoIterator<Item=(usize, usize)>, colour: Colour) -> Self {
+        let coords: HashSet<(usize, usize)> = coords.into_iter().collect();
+        self.layers.push(Layer {
+            mark: Box::new(move |cell_coords| coords.contains(&cell_coords).then_some(LayerChar::Keep)),
+            colour: Some(colour),
+        });
+        self
+    }
+
+    /// Return the character and colour shown at `coords`.
+    fn cell(&self, coords: (usize, usize)) -> (char, Option<Colour>) {
+        let base = (self.cell_char)(self.grid.get(coords).unwrap());
+        self.layers.iter().fold((base, None), |(c, colour), layer| {
+            match (layer.mark)(coords) {
+                Some(LayerChar::Keep) => (c, layer.colour.or(colour)),
+                Some(LayerChar::Replace(new_c)) => (new_c, layer.colour),
+                None => (c, colour),
+            }
+        })
+    }
+}
+
+impl<'a, G: GridLike> GridRenderer<'a, G> where G::Value: GridCell {
+    /// Render `grid` using `GridCell::to_char` for each cell.
+    pub fn from_cells(grid: &'a G) -> Self {
+        Self::new(grid, |v| v.to_char())
+    }
+}
+
+impl<G: GridLike> fmt::Display for GridRenderer<'_, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (height, width) = self.grid.shape();
+        let use_colour = self.colour_mode.enabled();
+        let border = self.border.chars();
+        if let Some((top_left, top_right, _, _, horizontal, _)) = border {
+            writeln!(f, "{top_left}{}{top_right}", horizontal.to_string().repeat(width))?;
+        }
+        for i in 0..height {
+            if let Some((_, _, _, _, _, vertical)) = border { write!(f, "{vertical}")?; }
+            for j in 0..width {
+                match self.cell((i, j)) {
+                    (c, Some(colour)) if use_colour => write!(f, "\x1b[{}m{c}\x1b[0m", colour.ansi_code())?,
+                    (c, _) => write!(f, "{c}")?,
+                }
+            }
+            if let Some((_, _, _, _, _, vertical)) = border { write!(f, "{vertical}")?; }
+            if i + 1 < height || border.is_some() { writeln!(f)?; }
+        }
+        if let Some((_, _, bottom_left, bottom_right, horizontal, _)) = border {
+            write!(f, "{bottom_left}{}{bottom_right}", horizontal.to_string().repeat(width))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use super::{DynGrid, GridError, GridLike, GridLikeMut, Neighbourhood};
+
+/// Distances and predecessors found by a grid search from a single start cell.
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    start: (usize, usize),
+    distances: DynGrid<Option<u64>>,
+    predecessors: DynGrid<Option<(usize, usize)>>,
+}
+
+impl SearchResult {
+    /// Create an empty result for a search from `start`, which must lie inside a grid of the given shape.
+    fn new(shape: (usize, usize), start: (usize, usize)) -> Result<Self, GridError> {
+        let mut result = Self {
+            start,
+            distances: DynGrid::create(shape),
+            predecessors: DynGrid::create(shape),
+        };
+        result.distances.set(start, Some(0))?;
+        Ok(result)
+    }
+
+    pub fn start(&self) -> (usize, usize) {
+        self.start
+    }
+
+    /// Return the distance from the start to `coords`, or `None` if it was not reached.
+    pub fn distance(&self, coords: (usize, usize)) -> Option<u64> {
+        self.distances.get(coords).ok().flatten()
+    }
+
+    /// Grid of distances from the start (`None` = not reached).
+    pub fn distances(&self) -> &DynGrid<Option<u64>> {
+        &self.distances
+    }
+
+    /// Grid of the cell each reached cell was entered from (`None` for the start and unreached cells).
+    pub fn predecessors(&self) -> &DynGrid<Option<(usize, usize)>> {
+        &self.predecessors
+    }
+
+    /// Reconstruct a shortest path from the start to `target`, both ends included.
+    pub fn path_to(&self, target: (usize, usize)) -> Option<Vec<(usize, usize)>> {
+        self.distance(target)?;
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(current).unwrap() {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search: every step has cost 1, cells for which `passable` is `false` are never entered.
+pub(super) fn bfs<G: GridLike>(grid: &G, start: (usize, usize), passable: impl Fn((usize, usize), G::Value) -> bool, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> {
+    let mut result = SearchResult::new(grid.shape(), start)?;
+    let mut queue = VecDeque::from([start]);
+    while let Some(coords) = queue.pop_front() {
+        let distance = result.distance(coords).unwrap();
+        for direction in neighbourhood.directions() {
+            let Ok(next) = grid.move_coords(coords, direction) else { continue };
+            if result.distance(next).is_some() || !passable(next, grid.get(next).unwrap()) {
+                continue;
+            }
+            result.distances.set(next, Some(distance + 1)).unwrap();
+            result.predecessors.set(next, Some(coords)).unwrap();
+            queue.push_back(next);
+        }
+    }
+    Ok(result)
+}
+
+/// Best-first search shared by Dijkstra and A*.
+/// Stops early once `goal` (if given) is taken from the queue.
+/// A cell is expanded again whenever a cheaper route to it is found, so the result is optimal
+/// for any admissible `heuristic`, although a consistent one expands every cell at most once.
+fn best_first<G: GridLike>(grid: &G, start: (usize, usize), goal: Option<(usize, usize)>, cost: impl Fn((usize, usize), G::Value) -> Option<u64>, heuristic: impl Fn((usize, usize)) -> u64, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> {
+    let mut result = SearchResult::new(grid.shape(), start)?;
+    if let Some(goal) = goal {
+        grid.get(goal)?;
+    }
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+    while let Some(Reverse((_, distance, coords))) = queue.pop() {
+        // skip entries superseded by a cheaper route
+        if result.distance(coords).is_some_and(|d| distance > d) { continue; }
+        if Some(coords) == goal { break; }
+        for direction in neighbourhood.directions() {
+            let Ok(next) = grid.move_coords(coords, direction) else { continue };
+            let Some(step_cost) = cost(next, grid.get(next).unwrap()) else { continue };
+            let next_distance = distance + step_cost;
+            if result.distance(next).is_none_or(|d| next_distance < d) {
+                result.distances.set(next, Some(next_distance)).unwrap();
+                result.predecessors.set(next, Some(coords)).unwrap();
+                queue.push(Reverse((next_distance + heuristic(next), next_distance, next)));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Dijkstra's algorithm: `cost` gives the cost of entering a cell, or `None` if it is impassable.
+pub(super) fn dijkstra<G: GridLike>(grid: &G, start: (usize, usize), cost: impl Fn((usize, usize), G::Value) -> Option<u64>, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> {
+    best_first(grid, start, None, cost, |_| 0, neighbourhood)
+}
+
+/// A* search from `start` to `goal`.
+/// `heuristic` must never overestimate the remaining cost (be admissible) for the result to be optimal.
+pub(super) fn astar<G: GridLike>(grid: &G, start: (usize, usize), goal: (usize, usize), cost: impl Fn((usize, usize), G::Value) -> Option<u64>, heuristic: impl Fn((usize, usize)) -> u64, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> {
+    best_first(grid, start, Some(goal), cost, heuristic, neighbourhood)
+}
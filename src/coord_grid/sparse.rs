@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use super::{Direction, DynGrid, GridLikeMut, Neighbourhood, Point};
+
+/// Unbounded grid storing only the cells that have been set, keyed by `Point`.
+/// Keeps track of the bounding box of all set cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounding_box: Option<(Point, Point)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self { cells: HashMap::new(), bounding_box: None }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Set the cell at `point`, returning its previous value.
+    pub fn insert(&mut self, point: Point, v: T) -> Option<T> {
+        self.bounding_box = Some(match self.bounding_box {
+            Some((min, max)) => (min.componentwise_min(&point), max.componentwise_max(&point)),
+            None => (point, point),
+        });
+        self.cells.insert(point, v)
+    }
+
+    /// Clear the cell at `point`, returning its value.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let v = self.cells.remove(&point)?;
+        if let Some((min, max)) = self.bounding_box {
+            // the bounding box can only shrink if the point was on its edge
+            if point.i == min.i || point.i == max.i || point.j == min.j || point.j == max.j {
+                self.bounding_box = self.cells.keys().fold(None, |bounding_box, p| match bounding_box {
+                    Some((min, max)) => Some((p.componentwise_min(&min), p.componentwise_max(&max))),
+                    None => Some((*p, *p)),
+                });
+            }
+        }
+        Some(v)
+    }
+
+    /// Top-left and bottom-right corners (both inclusive) of the set cells, `None` if there are none.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        self.bounding_box
+    }
+
+    /// Iterate over the set cells (in arbitrary order).
+    pub fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.cells.iter().map(|(point, v)| (*point, v))
+    }
+
+    /// Iterate over the set cells adjacent to `point`.
+    pub fn neighbours(&self, point: Point, neighbourhood: Neighbourhood) -> impl Iterator<Item=(Direction, Point, &T)> {
+        point.neighbours(neighbourhood.directions())
+            .filter_map(|(direction, next)| self.get(next).map(|v| (direction, next, v)))
+    }
+}
+
+impl<T: Copy + Debug + Default + PartialEq> SparseGrid<T> {
+    /// Copy the bounding box into a dense grid (unset cells are `T::default()`),
+    /// returning the position of its top-left corner alongside it.
+    pub fn to_dyn_grid(&self) -> Option<(Point, DynGrid<T>)> {
+        let (min, max) = self.bounding_box?;
+        let size = max - min;
+        let mut grid = DynGrid::create((size.i as usize + 1, size.j as usize + 1));
+        self.iter().for_each(|(point, v)| {
+            grid.set((point - min).try_into().unwrap(), *v).unwrap();
+        });
+        Some((min, grid))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        iter.into_iter().for_each(|(point, v)| { grid.insert(point, v); });
+        grid
+    }
+}
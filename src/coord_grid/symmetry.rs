@@ -0,0 +1,62 @@
+use strum_macros::EnumIter;
+
+/// One of the eight symmetries of a square (the dihedral group D4),
+/// applied to grids in `(i, j)` coordinates.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, EnumIter, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// Rotation by 90° clockwise
+    Rotate90,
+    /// Rotation by 180°
+    Rotate180,
+    /// Rotation by 270° clockwise
+    Rotate270,
+    /// Mirror image left to right (`j` reversed)
+    FlipHorizontal,
+    /// Mirror image top to bottom (`i` reversed)
+    FlipVertical,
+    /// Reflection in the main diagonal (`i` and `j` swapped)
+    Transpose,
+    /// Reflection in the anti-diagonal
+    AntiTranspose,
+}
+
+impl Symmetry {
+    /// Rotation by 90° clockwise `n_rot` times.
+    pub fn rotation(n_rot: usize) -> Self {
+        match n_rot % 4 {
+            0 => Symmetry::Identity,
+            1 => Symmetry::Rotate90,
+            2 => Symmetry::Rotate180,
+            3 => Symmetry::Rotate270,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Whether the transform swaps the height and width of a grid.
+    pub fn swaps_axes(&self) -> bool {
+        matches!(self, Symmetry::Rotate90 | Symmetry::Rotate270 | Symmetry::Transpose | Symmetry::AntiTranspose)
+    }
+
+    /// Return the `(height, width)` of a grid of the given shape after the transform.
+    pub fn transformed_shape(&self, shape: (usize, usize)) -> (usize, usize) {
+        let (height, width) = shape;
+        if self.swaps_axes() { (width, height) } else { (height, width) }
+    }
+
+    /// Map `coords` in a grid of the given shape to their position in the transformed grid.
+    pub fn transform_coords(&self, coords: (usize, usize), shape: (usize, usize)) -> (usize, usize) {
+        let (i, j) = coords;
+        let (height, width) = shape;
+        match self {
+            Symmetry::Identity => (i, j),
+            Symmetry::Rotate90 => (j, height - i - 1),
+            Symmetry::Rotate180 => (height - i - 1, width - j - 1),
+            Symmetry::Rotate270 => (width - j - 1, i),
+            Symmetry::FlipHorizontal => (i, width - j - 1),
+            Symmetry::FlipVertical => (height - i - 1, j),
+            Symmetry::Transpose => (j, i),
+            Symmetry::AntiTranspose => (width - j - 1, height - i - 1),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use super::{DynGrid, GridError, GridLike, GridLikeMut};
+
+/// Check that the rectangle of the given `shape` with its top-left corner at `offset`
+/// lies inside a grid of shape `grid_shape`.
+fn check_region(grid_shape: (usize, usize), offset: (usize, usize), shape: (usize, usize)) -> Result<(), GridError> {
+    let (i0, j0) = offset;
+    let (height, width) = shape;
+    if i0 + height <= grid_shape.0 && j0 + width <= grid_shape.1 {
+        Ok(())
+    } else {
+        Err(GridError::out_of_bounds(((i0 + height).saturating_sub(1), (j0 + width).saturating_sub(1)), grid_shape))
+    }
+}
+
+/// Read-only borrowed rectangular region of a grid.
+/// Coordinates are relative to the top-left corner of the region.
+#[derive(Debug)]
+pub struct GridView<'a, G: GridLike> {
+    grid: &'a G,
+    offset: (usize, usize),
+    shape: (usize, usize),
+}
+
+impl<G: GridLike> Clone for GridView<'_, G> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G: GridLike> Copy for GridView<'_, G> {}
+
+impl<'a, G: GridLike> GridView<'a, G> {
+    pub(super) fn new(grid: &'a G, offset: (usize, usize), shape: (usize, usize)) -> Result<Self, GridError> {
+        check_region(grid.shape(), offset, shape)?;
+        Ok(Self { grid, offset, shape })
+    }
+
+    /// Position of the top-left corner of the view in the underlying grid.
+    pub fn offset(&self) -> (usize, usize) {
+        self.offset
+    }
+
+    /// Borrow a region of this view (`offset` is relative to the view).
+    pub fn view(&self, offset: (usize, usize), shape: (usize, usize)) -> Result<GridView<'a, G>, GridError> {
+        check_region(self.shape, offset, shape)?;
+        Ok(Self { grid: self.grid, offset: (self.offset.0 + offset.0, self.offset.1 + offset.1), shape })
+    }
+
+    /// Iterate over all regions of the given shape inside this view, in row-major order of their top-left corners.
+    pub fn windows(&self, shape: (usize, usize)) -> impl Iterator<Item=GridView<'a, G>> + use<'a, G> {
+        let view = *self;
+        let n_i = (view.shape.0 + 1).saturating_sub(shape.0);
+        let n_j = (view.shape.1 + 1).saturating_sub(shape.1);
+        (0..n_i).flat_map(move |i| (0..n_j).map(move |j| view.view((i, j), shape).unwrap()))
+    }
+
+    /// Copy the contents of the view into a new grid.
+    pub fn to_dyn_grid(&self) -> DynGrid<G::Value> {
+        let mut new_grid = DynGrid::create(self.shape);
+        self.iter_values_with_coords().for_each(|(coords, v)| new_grid.set(coords, v).unwrap());
+        new_grid
+    }
+}
+
+impl<G: GridLike> GridLike for GridView<'_, G> {
+    type Value = G::Value;
+
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    fn get(&self, coords: (usize, usize)) -> Result<G::Value, GridError> {
+        if self.contains_coords(coords) {
+            self.grid.get((self.offset.0 + coords.0, self.offset.1 + coords.1))
+        } else {
+            Err(GridError::out_of_bounds(coords, self.shape))
+        }
+    }
+}
+
+/// Mutably borrowed rectangular region of a grid.
+/// Coordinates are relative to the top-left corner of the region.
+#[derive(Debug)]
+pub struct GridViewMut<'a, G: GridLike> {
+    grid: &'a mut G,
+    offset: (usize, usize),
+    shape: (usize, usize),
+}
+
+impl<'a, G: GridLikeMut> GridViewMut<'a, G> {
+    pub(super) fn new(grid: &'a mut G, offset: (usize, usize), shape: (usize, usize)) -> Result<Self, GridError> {
+        check_region(grid.shape(), offset, shape)?;
+        Ok(Self { grid, offset, shape })
+    }
+
+    /// Position of the top-left corner of the view in the underlying grid.
+    pub fn offset(&self) -> (usize, usize) {
+        self.offset
+    }
+}
+
+impl<G: GridLike> GridLike for GridViewMut<'_, G> {
+    type Value = G::Value;
+
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    fn get(&self, coords: (usize, usize)) -> Result<G::Value, GridError> {
+        if self.contains_coords(coords) {
+            self.grid.get((self.offset.0 + coords.0, self.offset.1 + coords.1))
+        } else {
+            Err(GridError::out_of_bounds(coords, self.shape))
+        }
+    }
+}
+
+impl<G: GridLikeMut> GridLikeMut for GridViewMut<'_, G> {
+    fn set(&mut self, coords: (usize, usize), v: G::Value) -> Result<(), GridError> {
+        if self.contains_coords(coords) {
+            self.grid.set((self.offset.0 + coords.0, self.offset.1 + coords.1), v)
+        } else {
+            Err(GridError::out_of_bounds(coords, self.shape))
+        }
+    }
+}
+
+/// Combine every cell of `other` with the cell of `grid` it covers when placed at `offset`.
+/// Nothing is changed if `other` does not fit.
+pub(super) fn blit<G: GridLikeMut, O: GridLike>(grid: &mut G, other: &O, offset: (usize, usize), merge: impl Fn(G::Value, O::Value) -> G::Value) -> Result<(), GridError> {
+    check_region(grid.shape(), offset, other.shape())?;
+    let (i0, j0) = offset;
+    for ((i, j), v) in other.iter_values_with_coords() {
+        let target = (i0 + i, j0 + j);
+        grid.set(target, merge(grid.get(target)?, v))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use std::error;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+/// Replace with the puzzle's day and part, then add the solution to `days::all()`.
+const DAY: u8 = 0;
+const PART: u8 = 0;
+
+fn calculate_result(input: &str) -> Result<usize, Box<dyn error::Error>> {
+    let lines: Vec<&str> = timing::parse(|| input.lines().collect());
+    Ok(lines.len())
+}
+
+pub struct DayXXPartX;
+
+impl Solution for DayXXPartX {
+    fn day(&self) -> u8 {
+        DAY
+    }
+
+    fn part(&self) -> u8 {
+        PART
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,30 @@
+use std::error;
+use crate::days::dial::Dial;
+use crate::days::dial_script;
+use crate::days::dial_script::ScriptError;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+fn calculate_result(input: &str) -> Result<usize, ScriptError> {
+    let mut dial = Dial::default();
+    let instructions = timing::parse(|| dial_script::parse(input))?;
+    dial_script::run(&instructions, &mut dial)?;
+    Ok(dial.zero_landings().try_into().expect("result too large"))
+}
+
+pub struct Day01Part1;
+
+impl Solution for Day01Part1 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,33 @@
+use std::error;
+use log::trace;
+use crate::days::dial::Dial;
+use crate::days::dial_script;
+use crate::days::dial_script::ScriptError;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+fn calculate_result(input: &str) -> Result<usize, ScriptError> {
+    let mut dial = Dial::default();
+    let instructions = timing::parse(|| dial_script::parse(input))?;
+    dial_script::run_with(&instructions, &mut dial, &mut |dial, direction, clicks, crossings| {
+        trace!("{clicks} {direction:?} ({crossings} zeros) -> {}", dial.position());
+    })?;
+    Ok(dial.zero_crossings().try_into().expect("result too large"))
+}
+
+pub struct Day01Part2;
+
+impl Solution for Day01Part2 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
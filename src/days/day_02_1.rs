@@ -0,0 +1,37 @@
+use std::error;
+use std::ops::RangeInclusive;
+use log::trace;
+use crate::days::repeated_ids::{parse_ranges, IdRule};
+use crate::parallel::map_records;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+/// Sum of the invalid IDs in a range: numbers made of some block of digits repeated twice
+fn check_range(range: &RangeInclusive<u64>) -> u64 {
+    let stats = IdRule::Exactly(2).stats(range.clone(), 10);
+    trace!("{} invalid IDs in {range:?}", stats.count);
+    stats.sum.try_into().expect("sum of invalid IDs too large")
+}
+
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let ranges = timing::parse(|| parse_ranges(input));
+    let result: u64 = map_records(&ranges, check_range).into_iter().sum();
+    Ok(result.try_into().expect("unable to convert result to usize"))
+}
+
+pub struct Day02Part1;
+
+impl Solution for Day02Part1 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,34 @@
+use std::error;
+use log::trace;
+use crate::days::digit_selection::{largest_subsequence, parse_banks, Decimal};
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+/// Number of batteries turned on in each bank
+const N_BATTERIES: usize = 12;
+
+fn calculate_result(input: &str) -> Result<Decimal, ()> {
+    let banks = timing::parse(|| parse_banks(input));
+    banks.iter().map(|bank| {
+        let selection = largest_subsequence(bank, N_BATTERIES).ok_or(())?;
+        trace!("{} from batteries {:?}", selection.value, selection.indices);
+        Ok(selection.value)
+    }).sum()
+}
+
+pub struct Day03Part2;
+
+impl Solution for Day03Part2 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "bank with fewer batteries than need to be turned on")?;
+        Ok(result.into())
+    }
+}
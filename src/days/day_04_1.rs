@@ -0,0 +1,54 @@
+use std::error;
+use crate::coord_grid::{DynGrid, GridCell, GridError, GridLike, Neighbourhood};
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum FloorCell {
+    #[default]
+    Empty,
+    PaperRoll,
+}
+
+impl GridCell for FloorCell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(FloorCell::Empty),
+            '@' => Some(FloorCell::PaperRoll),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            FloorCell::Empty => '.',
+            FloorCell::PaperRoll => '@',
+        }
+    }
+}
+
+fn calculate_result(input: &str) -> Result<usize, GridError> {
+    let grid: DynGrid<FloorCell> = timing::parse(|| input.parse())?;
+    let accessible_grid: DynGrid<bool> = grid.map_neighbourhood(Neighbourhood::Moore, |_, c, neighbours| {
+        c == FloorCell::PaperRoll && neighbours.iter().filter(|(_, _, n)| *n == FloorCell::PaperRoll).count() < 4
+    });
+    let total = accessible_grid.iter().filter(|accessible| **accessible).count();
+    Ok(total)
+}
+
+pub struct Day04Part1;
+
+impl Solution for Day04Part1 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
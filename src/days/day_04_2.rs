@@ -0,0 +1,71 @@
+use std::error;
+use log::debug;
+use crate::coord_grid::{Automaton, DynGrid, GridCell, GridError, GridLike, Neighbourhood};
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Hash)]
+enum FloorCell {
+    #[default]
+    Empty,
+    PaperRoll,
+}
+
+impl GridCell for FloorCell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(FloorCell::Empty),
+            '@' => Some(FloorCell::PaperRoll),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            FloorCell::Empty => '.',
+            FloorCell::PaperRoll => '@',
+        }
+    }
+}
+
+type PaperRollsGrid = DynGrid<FloorCell>;
+
+fn count_neighbouring_rolls(x: usize, y: usize, grid: &PaperRollsGrid) -> usize {
+    grid.count_neighbours((x, y), Neighbourhood::Moore, |c| c == FloorCell::PaperRoll)
+}
+
+fn remove_roll_if_accessible(grid: &PaperRollsGrid, (i, j): (usize, usize)) -> FloorCell {
+    match grid.get((i, j)).unwrap() {
+        FloorCell::PaperRoll if count_neighbouring_rolls(i, j, grid) < 4 => FloorCell::Empty,
+        c => c,
+    }
+}
+
+fn calculate_result(input: &str) -> Result<usize, GridError> {
+    let grid: PaperRollsGrid = timing::parse(|| input.parse())?;
+    let mut automaton = Automaton::new(grid, remove_roll_if_accessible);
+    automaton.run(None);
+    let mut total: usize = 0;
+    for removed in automaton.change_counts() {
+        total += removed;
+        debug!("{total} paper rolls removed so far")
+    }
+    Ok(total)
+}
+
+pub struct Day04Part2;
+
+impl Solution for Day04Part2 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
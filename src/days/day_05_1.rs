@@ -0,0 +1,66 @@
+use std::error;
+use log::debug;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+/// Range of IDs as parsed from file - ends inclusive
+#[derive(Debug)]
+struct IDRange(u64, u64);
+
+impl IDRange {
+    /// Parse a string slice into an `IDRange`, returning a `Result`.
+    fn from_string(spec: &str) -> Result<Self, ()> {
+        let bounds: Vec<&str> = spec.split("-").collect();
+        if bounds.len() == 2 {
+            Ok(IDRange(
+                bounds[0].parse::<u64>().expect("invalid left bound"),
+                bounds[1].parse::<u64>().expect("invalid right bound"),
+            ))
+        } else {
+            Err(())
+        }
+    }
+
+    /// Check whether this `IDRange` contains the specified ID.
+    fn contains(&self, id: &u64) -> bool {
+        *id >= self.0 && *id <= self.1
+    }
+}
+
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let (ranges, ids) = timing::parse(|| {
+        let mut lines = input.lines();
+        let ranges: Vec<IDRange> = lines
+            .by_ref()
+            .take_while(|l| l.trim() != "")
+            .map(|l| IDRange::from_string(l).expect("invalid ID range spec"))
+            .collect();
+        let ids: Vec<u64> = lines
+            .map(|l| l.parse::<u64>().expect("invalid ID"))
+            .collect();
+        (ranges, ids)
+    });
+    debug!("{ranges:?}");
+    debug!("{ids:?}");
+    // TODO: combine overlapping ranges for optimization
+    // However: not needed for part 1! Correct solution obtained in <1s
+    let total = ids.iter().filter(|id| ranges.iter().filter(|range| range.contains(id)).count() > 0).count();
+    Ok(total)
+}
+
+pub struct Day05Part1;
+
+impl Solution for Day05Part1 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
+}
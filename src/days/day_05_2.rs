@@ -1,9 +1,8 @@
 use std::cmp::max;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_05.txt";
+use std::error;
+use log::debug;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 /// Range of IDs as parsed from file - ends inclusive
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
@@ -22,8 +21,8 @@ impl IDRange {
         }
     }
 
-    /// Parse a `String` into an `IDRange`, returning a `Result`.
-    fn from_string(spec: String) -> Result<Self, ()> {
+    /// Parse a string slice into an `IDRange`, returning a `Result`.
+    fn from_string(spec: &str) -> Result<Self, ()> {
         let bounds: Vec<&str> = spec.split("-").collect();
         if bounds.len() == 2 {
             Ok(IDRange(
@@ -40,13 +39,13 @@ impl IDRange {
     }
 }
 
-fn calculate_result(mut lines: Lines<BufReader<File>>) -> Result<usize, ()> {
-    let ranges: Vec<IDRange> = lines
-        .by_ref()
-        .take_while(|l| l.as_ref().expect("invalid line").clone().trim() != "")
-        .map(|l| IDRange::from_string(l.unwrap()).expect("invalid ID range spec"))
-        .collect();
-    println!("{ranges:?}");
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let ranges: Vec<IDRange> = timing::parse(|| input
+        .lines()
+        .take_while(|l| l.trim() != "")
+        .map(|l| IDRange::from_string(l).expect("invalid ID range spec"))
+        .collect());
+    debug!("{ranges:?}");
     let mut ranges_deduplicated: Vec<IDRange> = ranges.clone();
     ranges_deduplicated.sort();
     let mut ranges_deduplicated_last: Vec<IDRange> = Vec::new();
@@ -65,15 +64,25 @@ fn calculate_result(mut lines: Lines<BufReader<File>>) -> Result<usize, ()> {
             }
             acc
         });
-        println!("Ranges after deduplicating: {ranges_deduplicated:?}")
+        debug!("Ranges after deduplicating: {ranges_deduplicated:?}")
     }
     let total: u64 = ranges_deduplicated.iter().map(|range| range.len()).sum();
     Ok(total.try_into().expect("result is invalid usize"))
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+pub struct Day05Part2;
+
+impl Solution for Day05Part2 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
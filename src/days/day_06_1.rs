@@ -0,0 +1,68 @@
+use std::error;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+type NumberLine = Vec<u64>;
+type OperationLine = Vec<Operation>;
+
+fn parse_number_line(line: &str) -> NumberLine {
+    line
+        .split_ascii_whitespace()
+        .map(|n| n.parse::<u64>().expect("invalid number"))
+        .collect()
+}
+
+fn parse_operation_line(line: &str) -> OperationLine {
+    line
+        .split_ascii_whitespace()
+        .map(|n| match n {
+            "+" => Operation::Add,
+            "*" => Operation::Multiply,
+            _ => panic!("invalid operation"),
+        })
+        .collect()
+}
+
+fn calculate_result(input: &str) -> Result<u64, ()> {
+    let (operation_line, number_lines) = timing::parse(|| {
+        let mut lines: Vec<&str> = input.lines().filter(|line| !line.trim().is_empty()).collect();
+        let operation_line: OperationLine = parse_operation_line(lines.pop().expect("operation line missing"));
+        let number_lines: Vec<NumberLine> = lines.into_iter().map(parse_number_line).collect();
+        (operation_line, number_lines)
+    });
+    if number_lines.iter().any(|line| line.len() != operation_line.len()) {
+        return Err(());
+    }
+    let total = (0..operation_line.len()).map(|i| {
+        let iter = number_lines.iter().map(|line| line[i]);
+        let result: u64 = match operation_line[i] {
+            Operation::Add => iter.sum(),
+            Operation::Multiply => iter.product(),
+        };
+        result
+    }).sum();
+    Ok(total)
+}
+
+pub struct Day06Part1;
+
+impl Solution for Day06Part1 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,87 @@
+use std::error;
+use log::trace;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+const SPACE_BYTE: u8 = 0x20;
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+type ProblemNumbers = Vec<u64>;
+type OperationLine = Vec<Operation>;
+
+fn parse_number_lines(lines: Vec<&str>) -> Vec<ProblemNumbers> {
+    // "transpose" input lines, padding short lines with spaces
+    let n_chars = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let lines_transp: Vec<String> = (0..n_chars).map(|j| {
+        let bytes: Vec<u8> = lines.iter().map(|line| *line.as_bytes().get(j).unwrap_or(&SPACE_BYTE)).collect();
+        String::from_utf8(bytes).unwrap()
+    }).collect();
+
+    // extract numbers
+    let mut problem_numbers: Vec<ProblemNumbers> = Vec::new();
+    problem_numbers.push(Vec::new());
+    lines_transp.into_iter().for_each(|line| {
+        if line.trim().is_empty() {
+            problem_numbers.push(Vec::new());
+        } else {
+            let idx = problem_numbers.len() - 1;
+            problem_numbers[idx].push(line.trim().parse().expect("invalid number"));
+        }
+    });
+    problem_numbers
+}
+
+fn parse_operation_line(line: &str) -> OperationLine {
+    line
+        .split_ascii_whitespace()
+        .map(|n| match n {
+            "+" => Operation::Add,
+            "*" => Operation::Multiply,
+            _ => panic!("invalid operation"),
+        })
+        .collect()
+}
+
+fn calculate_result(input: &str) -> Result<u64, ()> {
+    let (operation_line, numbers) = timing::parse(|| {
+        let mut lines: Vec<&str> = input.lines().filter(|line| !line.trim().is_empty()).collect();
+        let operation_line: OperationLine = parse_operation_line(lines.pop().expect("operation line missing"));
+        let numbers: Vec<ProblemNumbers> = parse_number_lines(lines);
+        (operation_line, numbers)
+    });
+    if numbers.len() != operation_line.len() {
+        return Err(());
+    }
+    let total = (0..operation_line.len()).map(|i| {
+        let iter = numbers[i].iter();
+        let result: u64 = match operation_line[i] {
+            Operation::Add => iter.sum(),
+            Operation::Multiply => iter.product(),
+        };
+        trace!("{:?} {:?} -> {}", operation_line[i], numbers[i], result);
+        result
+    }).sum();
+    Ok(total)
+}
+
+pub struct Day06Part2;
+
+impl Solution for Day06Part2 {
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,124 @@
+use std::cmp::PartialEq;
+use std::error;
+use log::debug;
+use crate::coord_grid::{BitGrid, Colour, ColourMode, DynGrid, GridCell, GridError, GridLike, GridLikeMut, GridRenderer};
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+type ManifoldDiagram<T> = DynGrid<T>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum ManifoldLayoutCell {
+    #[default]
+    Empty,
+    Source,
+    Splitter,
+}
+
+impl GridCell for ManifoldLayoutCell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(ManifoldLayoutCell::Empty),
+            'S' => Some(ManifoldLayoutCell::Source),
+            '^' => Some(ManifoldLayoutCell::Splitter),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            ManifoldLayoutCell::Empty => '.',
+            ManifoldLayoutCell::Source => 'S',
+            ManifoldLayoutCell::Splitter => '^',
+        }
+    }
+}
+
+type ManifoldLayoutDiagram = ManifoldDiagram<ManifoldLayoutCell>;
+type BeamPathDiagram = BitGrid;
+
+// Concept:
+// 1. Parse lines into map
+// 2. Find source
+// 3. Propagate beam through map
+// 4. Count splitters hit by beam (splitter with beam directly above)
+//      -> equals number of splits
+
+fn parse_manifold_layout(input: &str) -> Result<(ManifoldLayoutDiagram, usize), GridError> {
+    let layout = input.parse::<ManifoldLayoutDiagram>()?;
+    let source_j = layout.position(ManifoldLayoutCell::Source).map_or(0, |(_, j)| j);
+    Ok((layout, source_j))
+}
+
+fn push_if_not_present<T: PartialEq>(vec: &mut Vec<T>, elem: T) {
+    if !vec.contains(&elem) { vec.push(elem); }
+}
+
+fn propagate_beam(manifold_layout: &ManifoldLayoutDiagram, source_col: &usize) -> Result<BeamPathDiagram, GridError> {
+    let (diagram_height, _) = manifold_layout.shape();
+    let mut beam_paths = BeamPathDiagram::create(manifold_layout.shape());
+    let mut beam_ends: Vec<usize> = vec!(*source_col);
+    for row in 0..diagram_height {
+        let mut new_beam_ends = Vec::new();
+        for &j in beam_ends.iter() {
+            beam_paths.set((row, j), true)?;
+            if let Ok(ManifoldLayoutCell::Splitter) = manifold_layout.get((row, j)) {
+                push_if_not_present(&mut new_beam_ends, j - 1);
+                push_if_not_present(&mut new_beam_ends, j + 1);
+                beam_paths.set((row, j - 1), true)?;
+                beam_paths.set((row, j + 1), true)?;
+            } else {
+                new_beam_ends.push(j);
+            }
+        }
+        beam_ends = new_beam_ends;
+    }
+    Ok(beam_paths)
+}
+
+fn count_splits(manifold_layout: &ManifoldLayoutDiagram, beam_paths: &BeamPathDiagram) -> usize {
+    manifold_layout.iter_coords().filter(|(i, j)| {
+        if let Ok(ManifoldLayoutCell::Splitter) = manifold_layout.get((*i, *j)) {
+            beam_paths.get((i - 1, *j)).unwrap_or_default()
+        } else {
+            false
+        }
+    }).count()
+}
+
+fn print_diagram(manifold_layout: &ManifoldLayoutDiagram, beam_paths: Option<&BeamPathDiagram>) {
+    // the log may go to a file, where escape codes would only get in the way
+    let renderer = GridRenderer::from_cells(manifold_layout).colour_mode(ColourMode::Never);
+    match beam_paths {
+        Some(beam_paths) => debug!("{}", renderer
+            .overlay_grid(beam_paths, |beam| beam.then_some('|'), Some(Colour::Yellow))
+            .overlay_grid(manifold_layout, |cell| (cell != ManifoldLayoutCell::Empty).then(|| cell.to_char()), None)),
+        None => debug!("{renderer}"),
+    }
+}
+
+fn calculate_result(input: &str) -> Result<usize, GridError> {
+    let (manifold_layout, source_col) = timing::parse(|| parse_manifold_layout(input))?;
+    print_diagram(&manifold_layout, None);
+    let beam_paths = propagate_beam(&manifold_layout, &source_col)?;
+    print_diagram(&manifold_layout, Some(&beam_paths));
+    let n_splits = count_splits(&manifold_layout, &beam_paths);
+    Ok(n_splits)
+}
+
+pub struct Day07Part1;
+
+impl Solution for Day07Part1 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,101 @@
+use std::cmp::PartialEq;
+use std::error;
+use log::debug;
+use crate::coord_grid::{ColourMode, DynGrid, GridCell, GridError, GridLike, GridLikeMut, GridRenderer};
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum ManifoldLayoutCell {
+    #[default]
+    Empty,
+    Source,
+    Splitter,
+}
+
+impl GridCell for ManifoldLayoutCell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(ManifoldLayoutCell::Empty),
+            'S' => Some(ManifoldLayoutCell::Source),
+            '^' => Some(ManifoldLayoutCell::Splitter),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            ManifoldLayoutCell::Empty => '.',
+            ManifoldLayoutCell::Source => 'S',
+            ManifoldLayoutCell::Splitter => '^',
+        }
+    }
+}
+
+type ManifoldLayoutDiagram = DynGrid<ManifoldLayoutCell>;
+
+fn parse_manifold_layout(input: &str) -> Result<(ManifoldLayoutDiagram, usize), GridError> {
+    let layout = input.parse::<ManifoldLayoutDiagram>()?;
+    let source_j = layout.position(ManifoldLayoutCell::Source).map_or(0, |(_, j)| j);
+    Ok((layout, source_j))
+}
+
+fn count_multiverses(manifold_layout: &ManifoldLayoutDiagram, source_col: &usize) -> u64 {
+    let mut splitter_layout: DynGrid<bool> = DynGrid::create(manifold_layout.shape());
+    manifold_layout.iter_coords().for_each(|(i, j)| if let Ok(ManifoldLayoutCell::Splitter) = manifold_layout.get((i, j)) { splitter_layout.set((i, j), true).unwrap() });
+    let mut cache: DynGrid<Option<u64>> = DynGrid::create(manifold_layout.shape());
+    count_multiverses_inner(&splitter_layout, 0, *source_col, &mut cache)
+}
+
+/// Determine the number of multiverses from a given beam position,
+/// using recursion if a splitter is encountered
+/// and tracking previously visited positions using a cache
+/// (the latter reduces the runtime from >minutes to <1s).
+fn count_multiverses_inner(splitter_layout: &DynGrid<bool>, start_row: usize, col: usize, cache: &mut DynGrid<Option<u64>>) -> u64 {
+    let (diagram_height, _) = splitter_layout.shape();
+    let mut result = 1;
+    let mut row = start_row;
+    while row < diagram_height {
+        if let Ok(Some(value)) = cache.get((row, col)) {
+            result = value;
+            row += 1;  // needed for setting last cache row
+            break;
+        } else if splitter_layout.get((row, col)).unwrap() {
+            result = count_multiverses_inner(splitter_layout, row, col - 1, cache)
+                + count_multiverses_inner(splitter_layout, row, col + 1, cache);
+            row += 1;  // needed for setting last cache row
+            break;
+        }
+        row += 1;
+    }
+    (start_row..row).for_each(|r| cache.set((r, col), Some(result)).unwrap());
+    result
+}
+
+fn print_diagram(manifold_layout_diagram: &ManifoldLayoutDiagram) {
+    debug!("{}", GridRenderer::from_cells(manifold_layout_diagram).colour_mode(ColourMode::Never));
+}
+
+fn calculate_result(input: &str) -> Result<u64, GridError> {
+    let (manifold_layout, source_col) = timing::parse(|| parse_manifold_layout(input))?;
+    print_diagram(&manifold_layout);
+    let n_multiverses = count_multiverses(&manifold_layout, &source_col);
+    Ok(n_multiverses)
+}
+
+pub struct Day07Part2;
+
+impl Solution for Day07Part2 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input)?;
+        Ok(result.into())
+    }
+}
@@ -1,16 +1,19 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use std::error;
 use itertools::Itertools;
+use log::debug;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_08.txt";
-
-const N_JUNCTION_BOXES: usize = 1000;  // example: 20, real input: 1000
-const N_CONNECTIONS: usize = 1000;  // example: 10, real input: 1000
+const N_CONNECTIONS: usize = 1000;
+const N_CONNECTIONS_EXAMPLE: usize = 10;
+const N_JUNCTION_BOXES_EXAMPLE: usize = 20;
 const N_LARGEST_CIRCUITS: usize = 3;
 
 type CoordElement = i64;
 type Dist = f32;
+type ConnectionMatrix = Vec<Vec<bool>>;
+/// Two junction boxes with their indices
+type JunctionBoxPair<'a> = ((usize, &'a JunctionBox), (usize, &'a JunctionBox));
 
 #[derive(Clone, Copy, Debug)]
 struct JunctionBox {
@@ -30,7 +33,7 @@ impl JunctionBox {
     }
 
     /// Parse one line of input
-    fn from_string(input: String) -> Result<Self, ()> {
+    fn from_string(input: &str) -> Result<Self, ()> {
         let parts: Vec<&str> = input.trim().split(",").collect();
         if parts.len() != 3 {
             Err(())
@@ -47,26 +50,26 @@ impl JunctionBox {
     }
 }
 
-fn build_connection_matrix(pairs_sorted: Vec<((usize, &JunctionBox), (usize, &JunctionBox))>) -> [[bool; N_JUNCTION_BOXES]; N_JUNCTION_BOXES] {
-    let mut matrix = [[false; N_JUNCTION_BOXES]; N_JUNCTION_BOXES];
-    pairs_sorted.iter().take(N_CONNECTIONS).for_each(|((i, _), (j, _))| {
+fn build_connection_matrix(pairs_sorted: Vec<JunctionBoxPair>, n_junction_boxes: usize, n_connections: usize) -> ConnectionMatrix {
+    let mut matrix = vec![vec![false; n_junction_boxes]; n_junction_boxes];
+    pairs_sorted.iter().take(n_connections).for_each(|((i, _), (j, _))| {
         matrix[*i][*j] = true;
         matrix[*j][*i] = true;
     });
     matrix
 }
 
-fn extract_circuit_sizes(connection_matrix: [[bool; N_JUNCTION_BOXES]; N_JUNCTION_BOXES]) -> Vec<u64> {
-    let mut checked = [false; N_JUNCTION_BOXES];
+fn extract_circuit_sizes(connection_matrix: ConnectionMatrix) -> Vec<u64> {
+    let mut checked = vec![false; connection_matrix.len()];
     let mut circuit_sizes: Vec<u64> = Vec::new();
     while checked.iter().any(|v| !v) {
         let next_idx = checked.iter().enumerate().filter_map(|(i, &v)| if v { None } else { Some(i) }).next().unwrap();
         let mut circuit_idxs: Vec<usize> = vec!(next_idx);
         let mut idx = 0;
         while idx < circuit_idxs.len() {
-            let conn_vec = connection_matrix[circuit_idxs[idx]];
+            let conn_vec = &connection_matrix[circuit_idxs[idx]];
             let new_idxs: Vec<usize> = conn_vec.iter().enumerate().filter_map(|(i, &v)| if v { Some(i) } else { None }).collect();
-            new_idxs.iter().for_each(|i| if !circuit_idxs.contains(&i) { circuit_idxs.push(i.clone()) });
+            new_idxs.iter().for_each(|i| if !circuit_idxs.contains(i) { circuit_idxs.push(*i) });
             idx += 1;
         }
         circuit_idxs.iter().for_each(|i| checked[*i] = true);
@@ -77,24 +80,36 @@ fn extract_circuit_sizes(connection_matrix: [[bool; N_JUNCTION_BOXES]; N_JUNCTIO
     circuit_sizes
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<u64, ()> {
-    let junction_boxes: Vec<JunctionBox> = lines.map(|l| JunctionBox::from_string(l.expect("invalid line")).expect("unable to parse junction box")).collect();
-    let mut pairs: Vec<((usize, &JunctionBox), (usize, &JunctionBox))> = junction_boxes.iter().enumerate().tuple_combinations().collect();
+fn calculate_result(input: &str) -> Result<u64, ()> {
+    let junction_boxes: Vec<JunctionBox> = timing::parse(|| input.lines().map(|l| JunctionBox::from_string(l).expect("unable to parse junction box")).collect());
+    let mut pairs: Vec<JunctionBoxPair> = junction_boxes.iter().enumerate().tuple_combinations().collect();
     pairs.sort_by(|&((_ia1, &a1), (_ib1, &b1)), &((_ia2, &a2), (_ib2, &b2))| {
         let dist_1 = a1.dist(b1);
         let dist_2 = a2.dist(b2);
         dist_1.partial_cmp(&dist_2).expect("invalid_ordering")
     });
-    let connection_matrix = build_connection_matrix(pairs);
+    // the puzzle connects fewer pairs for the (much smaller) example
+    let n_connections = if junction_boxes.len() <= N_JUNCTION_BOXES_EXAMPLE { N_CONNECTIONS_EXAMPLE } else { N_CONNECTIONS };
+    let connection_matrix = build_connection_matrix(pairs, junction_boxes.len(), n_connections);
     let circuit_sizes = extract_circuit_sizes(connection_matrix);
-    println!("Circuit sizes: {circuit_sizes:?}");
+    debug!("Circuit sizes: {circuit_sizes:?}");
     let total = circuit_sizes.iter().take(N_LARGEST_CIRCUITS).product();
     Ok(total)
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+pub struct Day08Part1;
+
+impl Solution for Day08Part1 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
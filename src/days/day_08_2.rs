@@ -1,15 +1,14 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
+use std::error;
 use itertools::Itertools;
-
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_08.txt";
-
-const N_JUNCTION_BOXES: usize = 1000;  // example: 20, real input: 1000
-const N_LARGEST_CIRCUITS: usize = 3;
+use log::debug;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 type CoordElement = i64;
 type Dist = f32;
+type ConnectionMatrix = Vec<Vec<bool>>;
+/// Two junction boxes with their indices
+type JunctionBoxPair<'a> = ((usize, &'a JunctionBox), (usize, &'a JunctionBox));
 
 #[derive(Clone, Copy, Debug)]
 struct JunctionBox {
@@ -29,7 +28,7 @@ impl JunctionBox {
     }
 
     /// Parse one line of input
-    fn from_string(input: String) -> Result<Self, ()> {
+    fn from_string(input: &str) -> Result<Self, ()> {
         let parts: Vec<&str> = input.trim().split(",").collect();
         if parts.len() != 3 {
             Err(())
@@ -46,8 +45,8 @@ impl JunctionBox {
     }
 }
 
-fn build_connection_matrix(pairs_sorted: &Vec<((usize, &JunctionBox), (usize, &JunctionBox))>, n_connections: usize) -> ([[bool; N_JUNCTION_BOXES]; N_JUNCTION_BOXES], (usize, usize)) {
-    let mut matrix = [[false; N_JUNCTION_BOXES]; N_JUNCTION_BOXES];
+fn build_connection_matrix(pairs_sorted: &[JunctionBoxPair], n_junction_boxes: usize, n_connections: usize) -> (ConnectionMatrix, (usize, usize)) {
+    let mut matrix = vec![vec![false; n_junction_boxes]; n_junction_boxes];
     pairs_sorted.iter().take(n_connections).for_each(|((i, _), (j, _))| {
         matrix[*i][*j] = true;
         matrix[*j][*i] = true;
@@ -56,17 +55,17 @@ fn build_connection_matrix(pairs_sorted: &Vec<((usize, &JunctionBox), (usize, &J
     (matrix, (last_connection_i, last_connection_j))
 }
 
-fn extract_circuit_sizes(connection_matrix: [[bool; N_JUNCTION_BOXES]; N_JUNCTION_BOXES]) -> Vec<u64> {
-    let mut checked = [false; N_JUNCTION_BOXES];
+fn extract_circuit_sizes(connection_matrix: ConnectionMatrix) -> Vec<u64> {
+    let mut checked = vec![false; connection_matrix.len()];
     let mut circuit_sizes: Vec<u64> = Vec::new();
     while checked.iter().any(|v| !v) {
         let next_idx = checked.iter().enumerate().filter_map(|(i, &v)| if v { None } else { Some(i) }).next().unwrap();
         let mut circuit_idxs: Vec<usize> = vec!(next_idx);
         let mut idx = 0;
         while idx < circuit_idxs.len() {
-            let conn_vec = connection_matrix[circuit_idxs[idx]];
+            let conn_vec = &connection_matrix[circuit_idxs[idx]];
             let new_idxs: Vec<usize> = conn_vec.iter().enumerate().filter_map(|(i, &v)| if v { Some(i) } else { None }).collect();
-            new_idxs.iter().for_each(|i| if !circuit_idxs.contains(&i) { circuit_idxs.push(i.clone()) });
+            new_idxs.iter().for_each(|i| if !circuit_idxs.contains(i) { circuit_idxs.push(*i) });
             idx += 1;
         }
         circuit_idxs.iter().for_each(|i| checked[*i] = true);
@@ -77,9 +76,9 @@ fn extract_circuit_sizes(connection_matrix: [[bool; N_JUNCTION_BOXES]; N_JUNCTIO
     circuit_sizes
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<i64, ()> {
-    let junction_boxes: Vec<JunctionBox> = lines.map(|l| JunctionBox::from_string(l.expect("invalid line")).expect("unable to parse junction box")).collect();
-    let mut pairs: Vec<((usize, &JunctionBox), (usize, &JunctionBox))> = junction_boxes.iter().enumerate().tuple_combinations().collect();
+fn calculate_result(input: &str) -> Result<i64, ()> {
+    let junction_boxes: Vec<JunctionBox> = timing::parse(|| input.lines().map(|l| JunctionBox::from_string(l).expect("unable to parse junction box")).collect());
+    let mut pairs: Vec<JunctionBoxPair> = junction_boxes.iter().enumerate().tuple_combinations().collect();
     pairs.sort_by(|&((_ia1, &a1), (_ib1, &b1)), &((_ia2, &a2), (_ib2, &b2))| {
         let dist_1 = a1.dist(b1);
         let dist_2 = a2.dist(b2);
@@ -91,25 +90,35 @@ fn calculate_result(lines: Lines<BufReader<File>>) -> Result<i64, ()> {
     let mut n_connections = 1;
     while circuit_sizes.len() != 2 {  // Start binary-search-ish to reduce the total search time
         if circuit_sizes.len() > 2 { n_connections *= 2; } else { n_connections -= n_connections / 10; }
-        let (connection_matrix, _) = build_connection_matrix(&pairs, n_connections);
+        let (connection_matrix, _) = build_connection_matrix(&pairs, junction_boxes.len(), n_connections);
         circuit_sizes = extract_circuit_sizes(connection_matrix);
-        println!("{n_connections} connections: {} circuits", circuit_sizes.len());
+        debug!("{n_connections} connections: {} circuits", circuit_sizes.len());
     }
     while circuit_sizes.len() > 1 {  // Find the final connection one step at a time
         n_connections += 1;
-        let (connection_matrix, last_connection_idxs) = build_connection_matrix(&pairs, n_connections);
+        let (connection_matrix, last_connection_idxs) = build_connection_matrix(&pairs, junction_boxes.len(), n_connections);
         (last_connection_i, last_connection_j) = last_connection_idxs;
-        println!("Last connection: ({:?}, {:?})", junction_boxes[last_connection_i], junction_boxes[last_connection_j]);
+        debug!("Last connection: ({:?}, {:?})", junction_boxes[last_connection_i], junction_boxes[last_connection_j]);
         circuit_sizes = extract_circuit_sizes(connection_matrix);
-        println!("{n_connections} connections: {} circuits", circuit_sizes.len());
+        debug!("{n_connections} connections: {} circuits", circuit_sizes.len());
     }
     let total = junction_boxes[last_connection_i].x * junction_boxes[last_connection_j].x;
     Ok(total)
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+pub struct Day08Part2;
+
+impl Solution for Day08Part2 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
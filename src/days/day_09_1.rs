@@ -1,12 +1,9 @@
 use std::error;
-use std::fs::File;
 use std::io;
-use std::io::{BufRead, BufReader, Lines};
 use std::io::ErrorKind::InvalidInput;
 use itertools::Itertools;
-
-const OUTPUT_MESSAGE: &str = "Largest area";
-const INPUT_FILE: &str = "input/day_09.txt";
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 type CoordValue = usize;
 
@@ -14,7 +11,7 @@ type CoordValue = usize;
 struct RedTile(CoordValue, CoordValue);
 
 impl RedTile {
-    fn from_string(input: String) -> Result<Self, Box<dyn error::Error>> {
+    fn from_string(input: &str) -> Result<Self, Box<dyn error::Error>> {
         let parts: Vec<&str> = input.split(',').collect();
         if parts.len() != 2 { return Err(io::Error::new(InvalidInput, "invalid number of coordinates").into()); }
         Ok(RedTile(
@@ -28,12 +25,12 @@ impl RedTile {
     }
 }
 
-fn parse_tiles(lines: Lines<BufReader<File>>) -> Vec<RedTile> {
-    lines.map(|line| RedTile::from_string(line.expect("invalid line")).expect("invalid input")).collect()
+fn parse_tiles(input: &str) -> Vec<RedTile> {
+    input.lines().map(|line| RedTile::from_string(line).expect("invalid input")).collect()
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<CoordValue, ()> {
-    let tile_list = parse_tiles(lines);
+fn calculate_result(input: &str) -> Result<CoordValue, ()> {
+    let tile_list = timing::parse(|| parse_tiles(input));
     let result = tile_list.iter().tuple_combinations().map(|(a, b)| {
         let area = a.connected_area(b);
         //println!("{a:?} - {b:?} -> {area}");
@@ -42,9 +39,23 @@ fn calculate_result(lines: Lines<BufReader<File>>) -> Result<CoordValue, ()> {
     Ok(result.expect("no result returned"))
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+pub struct Day09Part1;
+
+impl Solution for Day09Part1 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn output_message(&self) -> &str {
+        "Largest area"
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
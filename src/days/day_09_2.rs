@@ -1,13 +1,11 @@
 use std::cmp::{min, max};
 use std::error;
-use std::fs::File;
 use std::io;
-use std::io::{BufRead, BufReader, Lines};
 use std::io::ErrorKind::InvalidInput;
 use itertools::Itertools;
-
-const OUTPUT_MESSAGE: &str = "Largest area";
-const INPUT_FILE: &str = "input/day_09.txt";
+use log::debug;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 type CoordValue = usize;
 
@@ -15,7 +13,7 @@ type CoordValue = usize;
 struct RedTile(CoordValue, CoordValue);
 
 impl RedTile {
-    fn from_string(input: String) -> Result<Self, Box<dyn error::Error>> {
+    fn from_string(input: &str) -> Result<Self, Box<dyn error::Error>> {
         let parts: Vec<&str> = input.split(',').collect();
         if parts.len() != 2 { return Err(io::Error::new(InvalidInput, "invalid number of coordinates").into()); }
         Ok(RedTile(
@@ -43,18 +41,6 @@ impl Rectangle {
         }
     }
 
-    fn contains(&self, x: &usize, y: &usize) -> bool {
-        *x >= self.left_x
-            && *x <= self.right_x
-            && *y >= self.bottom_y
-            && *y <= self.top_y
-    }
-
-    fn contains_in_perimeter(&self, x: &usize, y: &usize) -> bool {
-        ((*y == self.bottom_y || *y == self.top_y) && (*x >= self.left_x && *x <= self.right_x))
-            || ((*x == self.left_x || *x == self.right_x) && (*y >= self.bottom_y && *y <= self.top_y))
-    }
-
     fn contains_not_in_perimeter(&self, x: &usize, y: &usize) -> bool {
         *x > self.left_x
             && *x < self.right_x
@@ -66,7 +52,7 @@ impl Rectangle {
     ///
     /// **Idea:** A rectangle is invalid iff it contains a connection between
     /// two red tiles, except in its perimeter.
-    fn is_valid(&self, red_tile_connections: &Vec<(usize, usize)>) -> bool {
+    fn is_valid(&self, red_tile_connections: &[(usize, usize)]) -> bool {
         ! red_tile_connections.iter().any(|(x, y)| self.contains_not_in_perimeter(x, y))
     }
 
@@ -75,11 +61,11 @@ impl Rectangle {
     }
 }
 
-fn parse_tiles(lines: Lines<BufReader<File>>) -> Vec<RedTile> {
-    lines.map(|line| RedTile::from_string(line.expect("invalid line")).expect("invalid input")).collect()
+fn parse_tiles(input: &str) -> Vec<RedTile> {
+    input.lines().map(|line| RedTile::from_string(line).expect("invalid input")).collect()
 }
 
-fn find_red_tile_connections(tile_list: &Vec<RedTile>) -> Vec<(usize, usize)> {
+fn find_red_tile_connections(tile_list: &[RedTile]) -> Vec<(usize, usize)> {
     let mut red_tile_coords: Vec<(usize, usize)> = tile_list.iter().map(|tile| (tile.0, tile.1)).collect();
     let mut connection_coords = red_tile_coords.clone();
     let n_red_tiles = red_tile_coords.len();
@@ -104,11 +90,11 @@ fn find_red_tile_connections(tile_list: &Vec<RedTile>) -> Vec<(usize, usize)> {
     connection_coords
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<CoordValue, ()> {
-    let tile_list = parse_tiles(lines);
-    println!("{} red tiles parsed", tile_list.len());
+fn calculate_result(input: &str) -> Result<CoordValue, ()> {
+    let tile_list = timing::parse(|| parse_tiles(input));
+    debug!("{} red tiles parsed", tile_list.len());
     let red_tile_connections = find_red_tile_connections(&tile_list);
-    println!("{} connecting tiles found", red_tile_connections.len());
+    debug!("{} connecting tiles found", red_tile_connections.len());
     let result = tile_list.iter().tuple_combinations().filter_map(|(a, b)| {
         let rect = Rectangle::new(a, b);
         if rect.is_valid(&red_tile_connections) {
@@ -123,9 +109,23 @@ fn calculate_result(lines: Lines<BufReader<File>>) -> Result<CoordValue, ()> {
     Ok(result.expect("no result returned"))
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    println!("{OUTPUT_MESSAGE}: {result}");
+pub struct Day09Part2;
+
+impl Solution for Day09Part2 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn output_message(&self) -> &str {
+        "Largest area"
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-use std::time::Instant;
-
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_10.txt";
+use std::error;
+use log::{debug, trace};
+use crate::parallel::map_records;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 const MAX_PRESSES: usize = 16;  // maximum number of buttons to try before giving up
 const MAX_N_LIGHTS: usize = 10;  // maximum required array size
@@ -27,7 +26,7 @@ impl Machine {
         }
     }
 
-    fn from_spec(spec: String) -> Self {
+    fn from_spec(spec: &str) -> Self {
         let parts: Vec<&str> = spec.split(' ').collect();
         let spec_lights = &parts[0];
         let spec_buttons = &parts[1..(parts.len() - 1)];
@@ -47,14 +46,13 @@ impl Machine {
     }
 
     fn find_minimum_button_presses(&self) -> usize {
-        println!("{self:?}");
+        trace!("{self:?}");
         let mut n_presses = 1;
         while n_presses < MAX_PRESSES {
             let button_configs: Vec<Vec<usize>> = generate_button_configs(n_presses, self.button_wiring.len());
-            println!("Testing {} button pressing options with length {}", button_configs.len(), n_presses);
-            if let Some(buttons) = button_configs.into_iter().filter(|buttons| self.test_button_config(buttons)).next() {
-                println!("Valid combination found: {buttons:?} ({} presses)", buttons.len());
-                println!();
+            trace!("Testing {} button pressing options with length {}", button_configs.len(), n_presses);
+            if let Some(buttons) = button_configs.into_iter().find(|buttons| self.test_button_config(buttons)) {
+                debug!("Valid combination found: {buttons:?} ({} presses)", buttons.len());
                 return buttons.len();
             }
             n_presses += 1;
@@ -62,13 +60,12 @@ impl Machine {
         panic!("no valid button combination found");
     }
 
-    fn test_button_config(&self, buttons: &Vec<usize>) -> bool {
+    fn test_button_config(&self, buttons: &[usize]) -> bool {
         let mut light_states = [false; MAX_N_LIGHTS];
         buttons.iter().for_each(|&b| {
             self.button_wiring[b].iter().for_each(|&i| light_states[i] = !light_states[i]);
         });
-        let result = light_states == self.target_light_states;
-        result
+        light_states == self.target_light_states
     }
 }
 
@@ -96,24 +93,29 @@ fn generate_button_configs(n_presses: usize, n_buttons: usize) -> Vec<Vec<usize>
     }
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<usize, ()> {
-    println!("Parsing machine specs:");
-    let machines: Vec<Machine> = lines.map(|line| {
-        let machine = Machine::from_spec(line.expect("invalid line"));
-        println!("{machine:?}");
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let machines: Vec<Machine> = timing::parse(|| input.lines().map(|line| {
+        let machine = Machine::from_spec(line);
+        trace!("Parsed machine spec: {machine:?}");
         machine
-    }).collect();
-    println!();
-    let result = machines.iter().map(|machine| machine.find_minimum_button_presses()).sum();
+    }).collect());
+    let result = map_records(&machines, |machine| machine.find_minimum_button_presses()).into_iter().sum();
     Ok(result)
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let now = Instant::now();
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    let elapsed_time = now.elapsed();
-    println!("{OUTPUT_MESSAGE}: {result}");
-    println!("Result calculated in {} ms", elapsed_time.as_millis());
+pub struct Day10Part1;
+
+impl Solution for Day10Part1 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
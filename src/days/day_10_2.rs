@@ -1,10 +1,9 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-use std::time::Instant;
+use std::error;
 use z3::{ast::Int, Solver};
-
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_10.txt";
+use log::{debug, trace};
+use crate::parallel::map_records;
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 type CounterValue = u16;
 
@@ -27,7 +26,7 @@ impl Machine {
         }
     }
 
-    fn from_spec(spec: String) -> Self {
+    fn from_spec(spec: &str) -> Self {
         let parts: Vec<&str> = spec.split(' ').collect();
         let _spec_lights = &parts[0];
         let spec_buttons = &parts[1..(parts.len() - 1)];
@@ -75,7 +74,7 @@ impl Machine {
             .min_by_key(|presses| presses.iter().sum::<u64>())
         {
             let n_presses = best_solution.iter().sum();
-            println!("Best solution: {:?} ({} presses)", best_solution, n_presses);
+            debug!("Best solution: {:?} ({} presses)", best_solution, n_presses);
             n_presses
         } else {
             panic!("no solutions found");
@@ -83,24 +82,29 @@ impl Machine {
     }
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<u64, ()> {
-    println!("Parsing machine specs:");
-    let machines: Vec<Machine> = lines.map(|line| {
-        let machine = Machine::from_spec(line.expect("invalid line"));
-        println!("{machine:?}");
+fn calculate_result(input: &str) -> Result<u64, ()> {
+    let machines: Vec<Machine> = timing::parse(|| input.lines().map(|line| {
+        let machine = Machine::from_spec(line);
+        trace!("Parsed machine spec: {machine:?}");
         machine
-    }).collect();
-    println!();
-    let result = machines.iter().map(|machine| machine.find_minimum_button_presses()).sum();
+    }).collect());
+    let result = map_records(&machines, |machine| machine.find_minimum_button_presses()).into_iter().sum();
     Ok(result)
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let now = Instant::now();
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    let elapsed_time = now.elapsed();
-    println!("{OUTPUT_MESSAGE}: {result}");
-    println!("Result calculated in {} ms", elapsed_time.as_millis());
+pub struct Day10Part2;
+
+impl Solution for Day10Part2 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
@@ -1,10 +1,8 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{BufRead, BufReader, Lines};
-use std::time::Instant;
-
-const OUTPUT_MESSAGE: &str = "Total";
-const INPUT_FILE: &str = "input/day_11.txt";
+use std::error;
+use log::{debug, trace};
+use crate::solution::{Answer, Solution};
+use crate::timing;
 
 #[derive(Debug, Clone)]
 struct Device<'a> {
@@ -17,24 +15,23 @@ impl<'a> Device<'a> {
     }
 }
 
-fn parse_connections<'a>(lines: Lines<BufReader<File>>) -> HashMap<String, Vec<String>> {
+fn parse_connections(input: &str) -> HashMap<String, Vec<String>> {
     let mut devices: HashMap<String, Vec<String>> = HashMap::new();
-    lines.for_each(|line_res| {
-        let line = line_res.expect("invalid line");
+    input.lines().for_each(|line| {
         let device_label = line[..3].to_string();
         let connection_labels_single: Vec<String> = line[5..].split(' ').map(|s| s.to_string()).collect();
         devices.insert(device_label, connection_labels_single);
     });
     devices.insert("out".to_string(), Vec::new());
-    println!("{devices:?}");
+    trace!("{devices:?}");
     devices
 }
 
 fn count_paths(start_label: &str, end_label: &str, forbidden_labels: HashSet<&str>, devices: &HashMap<&str, Device>) -> usize {
-    println!("Counting paths from {start_label} to {end_label}, excluding the following: {forbidden_labels:?}");
+    debug!("Counting paths from {start_label} to {end_label}, excluding the following: {forbidden_labels:?}");
     let mut cache: HashMap<String, usize> = HashMap::new();
     let result = count_paths_inner(start_label, end_label, &forbidden_labels, devices, &mut cache);
-    println!("{result} paths found.");
+    debug!("{result} paths found.");
     result
 }
 
@@ -60,12 +57,12 @@ fn count_paths_inner(start_label: &str, end_label: &str, forbidden_labels: &Hash
     n_paths
 }
 
-fn calculate_result(lines: Lines<BufReader<File>>) -> Result<usize, ()> {
-    let connections = parse_connections(lines);
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let connections = timing::parse(|| parse_connections(input));
     let devices: HashMap<&str, Device> = connections.iter().map(|(label, connections)| {
         (&label[..], Device::new(connections.iter().map(|s| &s[..]).collect()))
     }).collect();
-    println!("Devices: {devices:?}");
+    debug!("Devices: {devices:?}");
 
     let valid_paths_1 = count_paths("svr", "fft", ["dac", "out"].into(), &devices)
         * count_paths("fft", "dac", ["svr", "out"].into(), &devices)
@@ -79,12 +76,19 @@ fn calculate_result(lines: Lines<BufReader<File>>) -> Result<usize, ()> {
     Ok(result)
 }
 
-fn main() {
-    let file = File::open(INPUT_FILE).expect("unable to open file");
-    let reader = BufReader::new(file);
-    let now = Instant::now();
-    let result = calculate_result(reader.lines()).expect("error calculating result");
-    let elapsed_time = now.elapsed();
-    println!("{OUTPUT_MESSAGE}: {result}");
-    println!("Result calculated in {} ms", elapsed_time.as_millis());
+pub struct Day11Part2;
+
+impl Solution for Day11Part2 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part(&self) -> u8 {
+        2
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
 }
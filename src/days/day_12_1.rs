@@ -0,0 +1,145 @@
+use std::error;
+use log::{debug, info};
+use crate::coord_grid::{BitGrid, Grid, GridLike, GridRenderer};
+use crate::parallel::map_records;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+
+const PRESENT_SIZE: usize = 3;
+const N_PRESENT_TYPES: usize = 6;
+
+type Present = Grid<PRESENT_SIZE, PRESENT_SIZE, bool>;
+
+/// Represents space below a tree as boolean values:
+/// `false` = no present, `true` = present.
+/// Uses a `BitGrid` so that placing a present is a few word operations.
+#[derive(Debug, Clone)]
+struct Tree {
+    grid: BitGrid,
+    presents: [usize; N_PRESENT_TYPES],
+}
+
+impl Tree {
+    fn new(size_i: usize, size_j: usize) -> Self {
+        Self { grid: BitGrid::create((size_i, size_j)), presents: [0; N_PRESENT_TYPES] }
+    }
+
+    #[allow(dead_code)]
+    fn print(&self) {
+        debug!("{}", GridRenderer::new(&self.grid, |v| if v { '#' } else { ' ' }));
+    }
+
+    /// Return a clone of `self` with the specified present
+    /// with the top-left corner at `coords`,
+    /// if the required space is free.
+    fn with_present(&self, coords: (usize, usize), present: &BitGrid) -> Result<Self, ()> {
+        match self.grid.overlaps(present, coords) {
+            Err(_) => Err(()),      // out of bounds
+            Ok(true) => Err(()),    // present collision
+            Ok(false) => {          // correct placement
+                let mut new_tree = self.clone();
+                new_tree.grid.union_at(present, coords).unwrap();
+                Ok(new_tree)
+            },
+        }
+    }
+
+    /// Iterate over the coordinates with free space
+    /// at which a present fits inside the tree.
+    fn iter_free_coords(&self) -> impl Iterator<Item=(usize, usize)> {
+        self.grid.windows((PRESENT_SIZE, PRESENT_SIZE))
+            .filter(|window| !window.get((0, 0)).unwrap())
+            .map(|window| window.offset())
+    }
+
+    /// Check whether a tree's presents can be positioned under it.
+    fn is_valid(&self, present_types: &[Present; N_PRESENT_TYPES]) -> bool {
+        let mut presents: Vec<Vec<BitGrid>> = Vec::new();
+        self.presents.iter().enumerate().for_each(|(i, n)| {
+            let orientations: Vec<BitGrid> = present_types[i].all_orientations().iter().map(BitGrid::from).collect();
+            (0..*n).for_each(|_| presents.push(orientations.clone()))
+        });
+        let (height, width) = self.grid.shape();
+        debug!("Assigning {} presents to a tree of size {height}x{width}...", presents.len());
+        //self.print();
+        let free_spaces: usize = height * width - self.grid.count_ones();
+        let required_spaces: usize = presents.iter().map(|orientations| orientations[0].count_ones()).sum();
+        if free_spaces < required_spaces {
+            debug!("Assignment requires more free spaces than are available.");
+            false
+        } else {
+            self.is_valid_inner(&presents, 0)
+        }
+    }
+
+    /// Recursive function to find a valid present placement
+    /// (`presents` contains the distinct orientations of each present to place).
+    fn is_valid_inner(&self, presents: &Vec<Vec<BitGrid>>, present_idx: usize) -> bool {
+        if present_idx >= presents.len() {
+            // End recursion
+            debug!("Assigned presents successfully");
+            //self.print();
+            return true
+        }
+
+        self.iter_free_coords().any(|coords| {
+            presents[present_idx].iter().any(|present| {
+                match self.with_present(coords, present) {
+                    Ok(new_tree) => new_tree.is_valid_inner(presents, present_idx + 1),
+                    Err(_) => false,
+                }
+            })
+        })
+    }
+}
+
+fn parse_input(input: &str) -> ([Present; N_PRESENT_TYPES], Vec<Tree>) {
+    let mut lines = input.lines();
+    let mut presents: [Present; 6] = (0..N_PRESENT_TYPES).map(|_| Present::create()).collect::<Vec<Present>>().try_into().unwrap();
+    let mut trees = Vec::new();
+
+    let mut present_idx: usize = 0;
+    while present_idx < N_PRESENT_TYPES {
+        lines.next();
+        presents[present_idx] = Present::from_lines(lines.by_ref().take(PRESENT_SIZE).map(|line| Ok(line.to_string()))).expect("invalid present shape");
+        if lines.next().expect("input incomplete").trim() != "" {
+            panic!("invalid input; lines missing between presents");
+        }
+        present_idx += 1;
+    }
+
+    lines.for_each(|line| {
+        let line_parts: Vec<&str> = line.split(':').collect();
+        let line_dims: [usize; 2] = line_parts[0].split('x').map(|n| n.parse::<usize>().expect("invalid dimension specifier")).collect::<Vec<usize>>().try_into().expect("incorrect number of dimensions");
+        let present_nums: [usize; N_PRESENT_TYPES] = line_parts[1].trim().split(' ').map(|n| n.parse::<usize>().expect("invalid number specifier")).collect::<Vec<usize>>().try_into().expect("incorrect number of present specifiers");
+        let mut tree = Tree::new(line_dims[0], line_dims[1]);
+        tree.presents = present_nums;
+        trees.push(tree);
+    });
+
+    (presents, trees)
+}
+
+fn calculate_result(input: &str) -> Result<usize, ()> {
+    let (presents, trees) = timing::parse(|| parse_input(input));
+    info!("Parsed {} present types and {} trees.", presents.len(), trees.len());
+    let result = map_records(&trees, |tree| tree.is_valid(&presents)).into_iter().filter(|valid| *valid).count();
+    Ok(result)
+}
+
+pub struct Day12Part1;
+
+impl Solution for Day12Part1 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part(&self) -> u8 {
+        1
+    }
+
+    fn solve(&self, input: &str) -> Result<Answer, Box<dyn error::Error>> {
+        let result = calculate_result(input).map_err(|_| "error calculating result")?;
+        Ok(result.into())
+    }
+}
@@ -0,0 +1,84 @@
+use crate::coord_grid::Turn;
+
+/// Number of positions on the puzzle's dial (0 to 99).
+pub const PUZZLE_DIAL_SIZE: u64 = 100;
+/// Position the puzzle's dial starts at.
+pub const PUZZLE_DIAL_START: u64 = 50;
+
+/// Circular dial with positions `0..size`, turning left (towards lower numbers)
+/// or right one click per position.
+/// Keeps count of how often it pointed at 0; every rotation is applied in constant time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    zero_landings: u64,
+    zero_crossings: u64,
+}
+
+impl Dial {
+    /// Create a dial with `size` positions, pointing at `start`.
+    /// Panics if `size` is 0 or `start` is not a position on the dial.
+    pub fn new(size: u64, start: u64) -> Self {
+        assert!(size > 0, "a dial needs at least one position");
+        assert!(start < size, "start position {start} is not on a dial of size {size}");
+        Self { size, position: start, zero_landings: 0, zero_crossings: 0 }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Number of rotations that ended at 0.
+    pub fn zero_landings(&self) -> u64 {
+        self.zero_landings
+    }
+
+    /// Number of clicks after which the dial pointed at 0,
+    /// including those during a rotation and those ending one.
+    pub fn zero_crossings(&self) -> u64 {
+        self.zero_crossings
+    }
+
+    /// Point the dial at `position` without turning it, so no zeros are counted.
+    /// Panics if `position` is not on the dial.
+    pub fn set_position(&mut self, position: u64) {
+        assert!(position < self.size, "position {position} is not on a dial of size {}", self.size);
+        self.position = position;
+    }
+
+    /// Turn the dial by `clicks`, returning how often it pointed at 0 on the way.
+    /// Panics for `Turn::Around`, which is not a dial rotation.
+    pub fn rotate(&mut self, turn: Turn, clicks: u64) -> u64 {
+        // computed in u128, as positions plus clicks can exceed u64::MAX
+        let (size, clicks) = (self.size as u128, clicks as u128);
+        let (crossings, position) = match turn {
+            Turn::Right => ((self.position as u128 + clicks) / size, (self.position as u128 + clicks) % size),
+            Turn::Left => {
+                // a left turn from `p` is a right turn from `-p` on the mirrored dial
+                let mirrored = (size - self.position as u128) % size;
+                ((mirrored + clicks) / size, (self.position as u128 + size - clicks % size) % size)
+            },
+            Turn::Around => panic!("a dial can only be turned left or right"),
+        };
+        // at most `clicks` crossings and a position below `size`, so both fit
+        let (crossings, position) = (crossings as u64, position as u64);
+        self.position = position;
+        self.zero_crossings += crossings;
+        if position == 0 {
+            self.zero_landings += 1;
+        }
+        crossings
+    }
+}
+
+/// The puzzle's dial: 100 positions, starting at 50.
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new(PUZZLE_DIAL_SIZE, PUZZLE_DIAL_START)
+    }
+}
@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fmt;
+use crate::coord_grid::Turn;
+use crate::days::dial::Dial;
+
+/// Operation of a single instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    Rotate(Turn, u64),
+    Set(u64),
+    Repeat(u64, Vec<Instruction>),
+}
+
+/// Instruction with the line and column (both 1-based) it starts at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Op,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Errors raised while parsing or running a script. All positions are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptError {
+    /// Character that does not start any instruction, number or brace.
+    UnexpectedChar { char: char, line: usize, column: usize },
+    /// Number too large to be represented.
+    InvalidNumber { number: String, line: usize, column: usize },
+    UnknownInstruction { word: String, line: usize, column: usize },
+    /// Something else than `expected` was found (`found` is `None` at the end of the script).
+    Expected { expected: &'static str, found: Option<String>, line: usize, column: usize },
+    /// `REPEAT` block at `line` and `column` without a closing brace.
+    UnclosedBlock { line: usize, column: usize },
+    /// `SET` to a position the dial does not have.
+    PositionOffDial { position: u64, size: u64, line: usize, column: usize },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::UnexpectedChar { char, line, column } => write!(f, "unexpected character {char:?} in line {line}, column {column}"),
+            ScriptError::InvalidNumber { number, line, column } => write!(f, "invalid number {number} in line {line}, column {column}"),
+            ScriptError::UnknownInstruction { word, line, column } => write!(f, "unknown instruction {word:?} in line {line}, column {column}"),
+            ScriptError::Expected { expected, found: Some(found), line, column } => write!(f, "expected {expected} in line {line}, column {column}, found {found:?}"),
+            ScriptError::Expected { expected, found: None, line, column } => write!(f, "expected {expected} in line {line}, column {column}, found end of script"),
+            ScriptError::UnclosedBlock { line, column } => write!(f, "block opened in line {line}, column {column} is never closed"),
+            ScriptError::PositionOffDial { position, size, line, column } => write!(f, "position {position} in line {line}, column {column} is not on a dial of size {size}"),
+        }
+    }
+}
+
+impl Error for ScriptError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Number(u64),
+    OpenBrace,
+    CloseBrace,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "{word}"),
+            Token::Number(n) => write!(f, "{n}"),
+            Token::OpenBrace => write!(f, "{{"),
+            Token::CloseBrace => write!(f, "}}"),
+        }
+    }
+}
+
+/// Token with its line and column.
+type Located<T> = (T, usize, usize);
+
+fn tokenize(script: &str) -> Result<Vec<Located<Token>>, ScriptError> {
+    let mut tokens = Vec::new();
+    for (line_idx, text) in script.lines().enumerate() {
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let (line, column) = (line_idx + 1, text[..start].chars().count() + 1);
+            let mut take_while = |predicate: fn(&char) -> bool| {
+                let mut end = start + c.len_utf8();
+                while let Some((i, next)) = chars.peek() {
+                    if !predicate(next) { break; }
+                    end = i + next.len_utf8();
+                    chars.next();
+                }
+                &text[start..end]
+            };
+            let token = match c {
+                '#' => break,
+                '/' if text[start..].starts_with("//") => break,
+                _ if c.is_whitespace() => continue,
+                '{' => Token::OpenBrace,
+                '}' => Token::CloseBrace,
+                _ if c.is_ascii_digit() => {
+                    let number = take_while(char::is_ascii_digit);
+                    Token::Number(number.parse().map_err(|_| ScriptError::InvalidNumber { number: number.to_string(), line, column })?)
+                },
+                _ if c.is_ascii_alphabetic() => Token::Word(take_while(char::is_ascii_alphabetic).to_string()),
+                _ => return Err(ScriptError::UnexpectedChar { char: c, line, column }),
+            };
+            tokens.push((token, line, column));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: std::vec::IntoIter<Located<Token>>,
+    /// Position just after the last token, reported for errors at the end of the script
+    end: (usize, usize),
+}
+
+impl Parser {
+    fn expected(expected: &'static str, found: Option<Located<Token>>, end: (usize, usize)) -> ScriptError {
+        match found {
+            Some((token, line, column)) => ScriptError::Expected { expected, found: Some(token.to_string()), line, column },
+            None => ScriptError::Expected { expected, found: None, line: end.0, column: end.1 },
+        }
+    }
+
+    fn number(&mut self) -> Result<u64, ScriptError> {
+        match self.tokens.next() {
+            Some((Token::Number(n), _, _)) => Ok(n),
+            found => Err(Self::expected("a number", found, self.end)),
+        }
+    }
+
+    /// Parse instructions up to the end of the script, or up to the closing brace
+    /// of the block opened at `block_start`.
+    fn instructions(&mut self, block_start: Option<(usize, usize)>) -> Result<Vec<Instruction>, ScriptError> {
+        let mut instructions = Vec::new();
+        loop {
+            let (token, line, column) = match self.tokens.next() {
+                Some(located) => located,
+                None => return match block_start {
+                    Some((line, column)) => Err(ScriptError::UnclosedBlock { line, column }),
+                    None => Ok(instructions),
+                },
+            };
+            let op = match token {
+                Token::CloseBrace if block_start.is_some() => return Ok(instructions),
+                Token::Word(word) => match word.as_str() {
+                    "L" | "R" => Op::Rotate(Turn::from_char(word.chars().next().unwrap()).unwrap(), self.number()?),
+                    "SET" => Op::Set(self.number()?),
+                    "REPEAT" => {
+                        let count = self.number()?;
+                        match self.tokens.next() {
+                            Some((Token::OpenBrace, _, _)) => Op::Repeat(count, self.instructions(Some((line, column)))?),
+                            found => return Err(Self::expected("{", found, self.end)),
+                        }
+                    },
+                    _ => return Err(ScriptError::UnknownInstruction { word, line, column }),
+                },
+                token => return Err(Self::expected("an instruction", Some((token, line, column)), self.end)),
+            };
+            instructions.push(Instruction { op, line, column });
+        }
+    }
+}
+
+/// Parse a script of dial rotations: the day 1 input, extended for writing tests by hand:
+///
+/// ```text
+/// # comments run to the end of the line (`//` works too)
+/// L68 R 30      # rotate left 68 / right 30 clicks
+/// SET 0         # point the dial at 0 without turning it
+/// REPEAT 3 {    # run the block 3 times (blocks can be nested)
+///     R100
+/// }
+/// ```
+/// Instructions are separated by any whitespace.
+pub fn parse(script: &str) -> Result<Vec<Instruction>, ScriptError> {
+    let tokens = tokenize(script)?;
+    let end = tokens.last().map_or((1, 1), |(token, line, column)| (*line, column + token.to_string().len()));
+    Parser { tokens: tokens.into_iter(), end }.instructions(None)
+}
+
+/// Apply `instructions` to `dial`, calling `on_rotation(dial, turn, clicks, crossings)` after every rotation.
+pub fn run_with(instructions: &[Instruction], dial: &mut Dial, on_rotation: &mut impl FnMut(&Dial, Turn, u64, u64)) -> Result<(), ScriptError> {
+    for instruction in instructions {
+        match &instruction.op {
+            Op::Rotate(turn, clicks) => {
+                let crossings = dial.rotate(*turn, *clicks);
+                on_rotation(dial, *turn, *clicks, crossings);
+            },
+            Op::Set(position) => {
+                if *position >= dial.size() {
+                    return Err(ScriptError::PositionOffDial { position: *position, size: dial.size(), line: instruction.line, column: instruction.column });
+                }
+                dial.set_position(*position);
+            },
+            Op::Repeat(count, body) => {
+                for _ in 0..*count {
+                    run_with(body, dial, on_rotation)?;
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Apply `instructions` to `dial`.
+pub fn run(instructions: &[Instruction], dial: &mut Dial) -> Result<(), ScriptError> {
+    run_with(instructions, dial, &mut |_, _, _, _| {})
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use crate::solution::Answer;
+
+/// Non-negative integer of any size, stored as decimal digits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Decimal {
+    /// Least significant digit first, without leading zeros (empty for 0)
+    digits: Vec<u8>,
+}
+
+impl Decimal {
+    /// Build a number from its digits, most significant first. Panics on values above 9.
+    pub fn from_digits(digits: &[u8]) -> Self {
+        assert!(digits.iter().all(|d| *d < 10), "not a decimal digit");
+        let mut digits: Vec<u8> = digits.iter().rev().copied().collect();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+
+    /// Return the value as `u64`, or `None` if it does not fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.digits.iter().rev().try_fold(0u64, |value, d| value.checked_mul(10)?.checked_add(*d as u64))
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(n: u64) -> Self {
+        Self::from_digits(&n.to_string().bytes().map(|b| b - b'0').collect::<Vec<u8>>())
+    }
+}
+
+impl Add for Decimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut digits = Vec::with_capacity(self.digits.len().max(other.digits.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.digits.len().max(other.digits.len()) {
+            let sum = self.digits.get(i).unwrap_or(&0) + other.digits.get(i).unwrap_or(&0) + carry;
+            digits.push(sum % 10);
+            carry = sum / 10;
+        }
+        if carry > 0 {
+            digits.push(carry);
+        }
+        Self { digits }
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        self.digits.iter().rev().try_for_each(|d| write!(f, "{d}"))
+    }
+}
+
+/// Digits picked from a bank, keeping their order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Positions of the picked digits in the bank, increasing
+    pub indices: Vec<usize>,
+    pub value: Decimal,
+}
+
+/// Pick `k` digits of `digits` (most significant first), keeping their order, so that they form the largest number.
+/// Returns `None` if there are fewer than `k` digits.
+///
+/// Digits are kept on a stack that is decreasing except for ties: a digit larger than the top of the stack
+/// replaces it while enough digits are left to fill the stack up to `k`, so every digit is pushed and popped at most once.
+pub fn largest_subsequence(digits: &[u8], k: usize) -> Option<Selection> {
+    let mut drops = digits.len().checked_sub(k)?;
+    let mut stack: Vec<usize> = Vec::with_capacity(digits.len());
+    for (i, digit) in digits.iter().enumerate() {
+        while drops > 0 && stack.last().is_some_and(|&top| digits[top] < *digit) {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(i);
+    }
+    stack.truncate(k);
+    let value = Decimal::from_digits(&stack.iter().map(|&i| digits[i]).collect::<Vec<u8>>());
+    Some(Selection { indices: stack, value })
+}
+
+/// Parse the puzzle input: one bank of digits per line.
+pub fn parse_banks(input: &str) -> Vec<Vec<u8>> {
+    input.lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).expect("invalid digit") as u8).collect())
+        .collect()
+}
+
+/// Small values are reported as numbers, larger ones as their decimal digits.
+impl From<Decimal> for Answer {
+    fn from(n: Decimal) -> Self {
+        n.to_u64().map_or_else(|| Answer::Text(n.to_string()), Answer::Unsigned)
+    }
+}
@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, RangeInclusive};
+use std::str::FromStr;
+
+/// Number and sum of a set of IDs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IdStats {
+    pub count: u64,
+    pub sum: u128,
+}
+
+impl Add for IdStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { count: self.count + other.count, sum: self.sum + other.sum }
+    }
+}
+
+impl Sum for IdStats {
+    fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Parse the puzzle input: a single line of comma-separated ranges such as `11-22,95-115`.
+pub fn parse_ranges(input: &str) -> Vec<RangeInclusive<u64>> {
+    let ranges_str = input.lines().next().expect("no line found");
+    ranges_str.split(",").map(|r| {
+        let mut r_iter = r.split("-");
+        let a = r_iter.next().expect("1st bound missing").parse::<u64>().expect("invalid number");
+        let b = r_iter.next().expect("2nd bound missing").parse::<u64>().expect("invalid number");
+        a..=b
+    }).collect()
+}
+
+/// Digits of `n` in `base`, most significant first.
+pub fn digits(mut n: u64, base: u32) -> Vec<u32> {
+    let mut digits = vec![(n % base as u64) as u32];
+    while n >= base as u64 {
+        n /= base as u64;
+        digits.push((n % base as u64) as u32);
+    }
+    digits.reverse();
+    digits
+}
+
+/// Number of digits of `n` in `base`.
+fn digit_count(n: u64, base: u32) -> u32 {
+    n.checked_ilog(base as u64).map_or(1, |log| log + 1)
+}
+
+/// Factor turning a block of `block_len` digits into `k` copies of itself,
+/// e.g. 1001001 for a block length of 3, `k = 3` and base 10.
+pub fn repetition_factor(block_len: u32, k: u32, base: u32) -> u128 {
+    (0..k).map(|i| (base as u128).pow(i * block_len)).sum()
+}
+
+/// Blocks `x` of exactly `block_len` digits (no leading zero) with `x * factor` in `range`.
+fn block_range(range: &RangeInclusive<u64>, block_len: u32, factor: u128, base: u32) -> RangeInclusive<u128> {
+    let (a, b) = (*range.start() as u128, *range.end() as u128);
+    let lo = (base as u128).pow(block_len - 1).max(a.div_ceil(factor));
+    let hi = ((base as u128).pow(block_len) - 1).min(b / factor);
+    lo..=hi
+}
+
+/// Digit counts of the numbers in `range`.
+fn digit_counts(range: &RangeInclusive<u64>, base: u32) -> RangeInclusive<u32> {
+    digit_count(*range.start(), base)..=digit_count(*range.end(), base)
+}
+
+/// Enumerate the numbers in `range` consisting of `k` copies of a block of digits in `base`, in increasing order.
+/// Numbers repeating a shorter block (such as 1111 = "11" twice) are included once.
+/// Blocks never start with a zero, so 0 is not a repetition of anything, even for `k = 1`.
+pub fn repetitions(range: RangeInclusive<u64>, k: u32, base: u32) -> impl Iterator<Item=u64> {
+    digit_counts(&range, base).filter(move |d| k > 0 && d.is_multiple_of(k)).flat_map(move |d| {
+        let factor = repetition_factor(d / k, k, base);
+        block_range(&range, d / k, factor, base).map(move |x| (x * factor) as u64)
+    })
+}
+
+/// Count and sum of the `d`-digit numbers in `range` consisting of `k` copies of a block, without enumerating them.
+fn repetition_stats_with_digits(range: &RangeInclusive<u64>, d: u32, k: u32, base: u32) -> IdStats {
+    let factor = repetition_factor(d / k, k, base);
+    let blocks = block_range(range, d / k, factor, base);
+    if blocks.is_empty() {
+        return IdStats::default();
+    }
+    let (lo, hi) = blocks.into_inner();
+    let count = hi - lo + 1;
+    IdStats { count: count as u64, sum: factor * (lo + hi) * count / 2 }
+}
+
+/// Count and sum of `repetitions(range, k, base)`, computed in closed form.
+pub fn repetition_stats(range: RangeInclusive<u64>, k: u32, base: u32) -> IdStats {
+    digit_counts(&range, base)
+        .filter(|d| k > 0 && d.is_multiple_of(k))
+        .map(|d| repetition_stats_with_digits(&range, d, k, base))
+        .sum()
+}
+
+/// Möbius function: 0 if `n` has a squared prime factor, otherwise -1 to the power of its number of prime factors.
+fn mobius(mut n: u32) -> i128 {
+    let mut result = 1;
+    let mut p = 2;
+    while n > 1 {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+    result
+}
+
+/// Count and sum of the numbers in `range` consisting of at least `n` copies of a block of digits in `base`.
+///
+/// Every `d`-digit number is made of `m` copies of its shortest repeating block, and of `k` copies of a block
+/// exactly for the `k` dividing `m`. The numbers with a given `m` are found by inclusion-exclusion (Möbius
+/// inversion) over the multiples `k` of `m` dividing `d`, so numbers repeating several ways are counted once.
+pub fn repeated_at_least_stats(range: RangeInclusive<u64>, n: u32, base: u32) -> IdStats {
+    let (mut count, mut sum): (i128, i128) = (0, 0);
+    for d in digit_counts(&range, base) {
+        let divisors: Vec<u32> = (1..=d).filter(|k| d.is_multiple_of(*k)).collect();
+        let repeated: Vec<IdStats> = divisors.iter().map(|&k| repetition_stats_with_digits(&range, d, k, base)).collect();
+        for &m in divisors.iter().filter(|&&m| m >= n) {
+            for (&k, stats) in divisors.iter().zip(&repeated).filter(|(k, _)| k.is_multiple_of(m)) {
+                let sign = mobius(k / m);
+                count += sign * stats.count as i128;
+                sum += sign * stats.sum as i128;
+            }
+        }
+    }
+    IdStats { count: count as u64, sum: sum as u128 }
+}
+
+/// What makes an ID invalid, judged on its digits in a given base.
+#[derive(Debug, Clone, Copy)]
+pub enum IdRule {
+    /// Some block of digits repeated exactly `n` times (1111 is "11" repeated twice)
+    Exactly(u32),
+    /// Some block of digits repeated `n` or more times
+    AtLeast(u32),
+    /// Digits reading the same in both directions
+    Palindrome,
+    /// Any predicate on the digits, most significant first
+    Custom(fn(&[u32]) -> bool),
+}
+
+impl IdRule {
+    /// Check a single ID. Like `repetitions`, the repetition rules never match 0.
+    pub fn is_invalid(&self, id: u64, base: u32) -> bool {
+        let digits = digits(id, base);
+        let repeats = |k: usize| id > 0 && digits.len().is_multiple_of(k) && digits.chunks(digits.len() / k).all(|block| block == &digits[..digits.len() / k]);
+        match self {
+            IdRule::Exactly(n) => *n > 0 && repeats(*n as usize),
+            IdRule::AtLeast(n) => ((*n).max(1) as usize..=digits.len()).any(repeats),
+            IdRule::Palindrome => digits.iter().eq(digits.iter().rev()),
+            IdRule::Custom(predicate) => predicate(&digits),
+        }
+    }
+
+    /// Count and sum of the invalid IDs in `range`. Repetition rules are computed without
+    /// enumerating the range, the others check every ID.
+    pub fn stats(&self, range: RangeInclusive<u64>, base: u32) -> IdStats {
+        match self {
+            IdRule::Exactly(n) => repetition_stats(range, *n, base),
+            IdRule::AtLeast(n) => repeated_at_least_stats(range, *n, base),
+            _ => range.filter(|&id| self.is_invalid(id, base)).map(|id| IdStats { count: 1, sum: id as u128 }).sum(),
+        }
+    }
+}
+
+/// Parse `exactly:<n>`, `at-least:<n>` or `palindrome`. Custom rules can only be built in code.
+impl FromStr for IdRule {
+    type Err = IdRuleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IdRuleError::InvalidRule(s.to_string());
+        let count = |n: &str| n.parse::<u32>().ok().filter(|n| *n > 0).ok_or_else(invalid);
+        match s.split_once(':') {
+            Some(("exactly", n)) => Ok(IdRule::Exactly(count(n)?)),
+            Some(("at-least", n)) => Ok(IdRule::AtLeast(count(n)?)),
+            None if s == "palindrome" => Ok(IdRule::Palindrome),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for IdRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdRule::Exactly(n) => write!(f, "exactly:{n}"),
+            IdRule::AtLeast(n) => write!(f, "at-least:{n}"),
+            IdRule::Palindrome => write!(f, "palindrome"),
+            IdRule::Custom(_) => write!(f, "custom"),
+        }
+    }
+}
+
+/// Errors raised while reading a rule or base given as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdRuleError {
+    InvalidRule(String),
+    /// Bases below 2 have no positional digits
+    InvalidBase(String),
+}
+
+impl fmt::Display for IdRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdRuleError::InvalidRule(rule) => write!(f, "invalid rule {rule:?}, expected exactly:<n>, at-least:<n> or palindrome"),
+            IdRuleError::InvalidBase(base) => write!(f, "invalid base {base:?}, expected a number of at least 2"),
+        }
+    }
+}
+
+impl Error for IdRuleError {}
+
+/// Parse a number base of at least 2.
+pub fn parse_base(s: &str) -> Result<u32, IdRuleError> {
+    s.parse::<u32>().ok().filter(|base| *base >= 2).ok_or_else(|| IdRuleError::InvalidBase(s.to_string()))
+}
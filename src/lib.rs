@@ -1,59 +1,351 @@
 pub mod coord_grid {
+    use std::error::Error;
+    use std::fmt;
     use std::fmt::Debug;
-    use std::fs::File;
-    use std::io::{BufReader, Lines};
+    use std::io;
+    use std::io::BufRead;
+    use std::str::FromStr;
+    use strum::IntoEnumIterator;
     use strum_macros::EnumIter;
 
-    #[derive(Debug, Clone)]
-    pub struct Grid<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> {
-        _grid: [[T; GRID_WIDTH]; GRID_HEIGHT],
+    mod automaton;
+    mod bit_grid;
+    mod components;
+    mod heading;
+    #[cfg(feature = "parallel")]
+    mod parallel;
+    mod point;
+    mod render;
+    mod search;
+    mod sparse;
+    mod symmetry;
+    mod view;
+
+    pub use automaton::{Automaton, RunOutcome, UpdateMode};
+    pub use bit_grid::BitGrid;
+    pub use components::{Component, Components};
+    pub use heading::Heading;
+    #[cfg(feature = "parallel")]
+    pub use parallel::ParallelGridLike;
+    pub use point::{Point, Vec2};
+    pub use render::{Border, Colour, ColourMode, GridRenderer};
+    pub use search::SearchResult;
+    pub use sparse::SparseGrid;
+    pub use symmetry::Symmetry;
+    pub use view::{GridView, GridViewMut};
+
+    /// Errors raised by grid accesses and grid parsing.
+    #[derive(Debug)]
+    pub enum GridError {
+        /// `coords` lie outside a grid with the given `(height, width)`.
+        OutOfBounds { coords: (isize, isize), shape: (usize, usize) },
+        /// The input contained `found` lines instead of `expected`.
+        WrongLineCount { found: usize, expected: usize },
+        /// Line number `line` (1-based) had `found` characters instead of `expected`.
+        WrongLineLength { line: usize, found: usize, expected: usize },
+        /// Two grids (or a grid and its target type) have incompatible shapes.
+        WrongShape { found: (usize, usize), expected: (usize, usize) },
+        /// Character `char` at `line` and `column` (both 1-based) does not map to a cell value.
+        InvalidChar { char: char, line: usize, column: usize },
+        Io(io::Error),
     }
 
-    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> Grid<GRID_WIDTH, GRID_HEIGHT, T> {
-        pub fn create() -> Self {
-            Self { _grid: [[T::default(); GRID_WIDTH]; GRID_HEIGHT] }
+    impl GridError {
+        fn out_of_bounds(coords: (usize, usize), shape: (usize, usize)) -> Self {
+            let coords = (coords.0.try_into().unwrap_or(isize::MAX), coords.1.try_into().unwrap_or(isize::MAX));
+            GridError::OutOfBounds { coords, shape }
         }
+    }
 
-        pub fn shape(&self) -> (usize, usize) {
-            (GRID_HEIGHT, GRID_WIDTH)
+    impl fmt::Display for GridError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                GridError::OutOfBounds { coords, shape } => write!(f, "coordinates {coords:?} out of bounds for grid of shape {shape:?}"),
+                GridError::WrongLineCount { found, expected } => write!(f, "incorrect number of lines: found {found}, expected {expected}"),
+                GridError::WrongLineLength { line, found, expected } => write!(f, "incorrect length of line {line}: found {found}, expected {expected} characters"),
+                GridError::WrongShape { found, expected } => write!(f, "incorrect grid shape: found {found:?}, expected {expected:?}"),
+                GridError::InvalidChar { char, line, column } => write!(f, "invalid character {char:?} in line {line}, column {column}"),
+                GridError::Io(e) => write!(f, "unable to read grid: {e}"),
+            }
         }
+    }
 
-        pub fn set_all(&mut self, v: T) {
-            for i in 0..GRID_HEIGHT {
-                for j in 0..GRID_WIDTH {
-                    self._grid[i][j] = v;
-                }
+    impl Error for GridError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                GridError::Io(e) => Some(e),
+                _ => None,
             }
         }
+    }
 
-        pub fn contains_coords(&self, coords: (usize, usize)) -> bool {
-            let (i, j) = coords;
-            i < GRID_HEIGHT && j < GRID_WIDTH
+    impl From<io::Error> for GridError {
+        fn from(e: io::Error) -> Self {
+            GridError::Io(e)
+        }
+    }
+
+    /// Mapping between grid cell values and their characters in puzzle input,
+    /// used for parsing and printing grids.
+    pub trait GridCell: Copy + Debug + Default + PartialEq {
+        /// Return the cell value for `c`, or `None` if `c` is not a valid cell.
+        fn from_char(c: char) -> Option<Self>;
+
+        fn to_char(&self) -> char;
+    }
+
+    impl GridCell for char {
+        fn from_char(c: char) -> Option<Self> {
+            Some(c)
+        }
+
+        fn to_char(&self) -> char {
+            *self
+        }
+    }
+
+    /// `#` = `true`, `.` = `false`
+    impl GridCell for bool {
+        fn from_char(c: char) -> Option<Self> {
+            match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            }
+        }
+
+        fn to_char(&self) -> char {
+            if *self { '#' } else { '.' }
+        }
+    }
+
+    /// Parse lines into a `DynGrid`, requiring all lines to have the same length
+    /// (or `expected_width`, if given).
+    fn parse_cells<T: GridCell>(lines: impl Iterator<Item=io::Result<String>>, expected_width: Option<usize>) -> Result<DynGrid<T>, GridError> {
+        let mut values: Vec<T> = Vec::new();
+        let mut width = expected_width;
+        let mut num_lines = 0;
+        for l in lines {
+            let line = l?;
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(w) if w != line_width => {
+                    return Err(GridError::WrongLineLength { line: num_lines + 1, found: line_width, expected: w });
+                },
+                Some(_) => (),
+            }
+            for (j, c) in line.chars().enumerate() {
+                values.push(T::from_char(c).ok_or(GridError::InvalidChar { char: c, line: num_lines + 1, column: j + 1 })?);
+            }
+            num_lines += 1;
         }
+        Ok(DynGrid { _grid: values, _height: num_lines, _width: width.unwrap_or(0) })
+    }
+
+    fn fmt_rows<'a, T: GridCell + 'a>(f: &mut fmt::Formatter<'_>, rows: impl Iterator<Item=&'a [T]>) -> fmt::Result {
+        for (i, row) in rows.enumerate() {
+            if i > 0 { writeln!(f)?; }
+            let line: String = row.iter().map(|v| v.to_char()).collect();
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
 
-        pub fn contains_coords_signed(&self, coords: (isize, isize)) -> bool {
+    /// Shared read-only interface of `Grid`, `DynGrid` and their views.
+    ///
+    /// Implementors only need to provide `shape` and `get`;
+    /// everything else is built on top of these.
+    pub trait GridLike {
+        type Value: Copy + Debug + Default + PartialEq;
+
+        /// Return the grid dimensions as `(height, width)`.
+        fn shape(&self) -> (usize, usize);
+
+        fn get(&self, coords: (usize, usize)) -> Result<Self::Value, GridError>;
+
+        fn contains_coords(&self, coords: (usize, usize)) -> bool {
             let (i, j) = coords;
-            i >= 0 && j >= 0 && self.contains_coords((i.try_into().unwrap(), j.try_into().unwrap()))
+            let (height, width) = self.shape();
+            i < height && j < width
+        }
+
+        fn contains_coords_signed(&self, coords: (isize, isize)) -> bool {
+            <(usize, usize)>::try_from(Point::from(coords)).is_ok_and(|coords| self.contains_coords(coords))
+        }
+
+        fn iter_coords(&self) -> impl Iterator<Item=(usize, usize)> {
+            let (height, width) = self.shape();
+            (0..height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+        }
+
+        fn iter_values_with_coords(&self) -> impl Iterator<Item=((usize, usize), Self::Value)> {
+            self.iter_coords().map(move |coords| (coords, self.get(coords).unwrap()))
+        }
+
+        /// Move one step from `coords` in `direction`,
+        /// returning `GridError::OutOfBounds` with the target coordinates if the step leaves the grid.
+        fn move_coords(&self, coords: (usize, usize), direction: Direction) -> Result<(usize, usize), GridError> {
+            let (i, j) = self.force_move_coords(coords, direction);
+            if self.contains_coords_signed((i, j)) {
+                Ok((i.try_into().unwrap(), j.try_into().unwrap()))
+            } else {
+                Err(GridError::OutOfBounds { coords: (i, j), shape: self.shape() })
+            }
+        }
+
+        fn force_move_coords(&self, coords: (usize, usize), direction: Direction) -> (isize, isize) {
+            Point::from(coords).step(direction).into()
+        }
+
+        fn position(&self, needle: Self::Value) -> Option<(usize, usize)> {
+            self.iter_values_with_coords().find(|(_, v)| *v == needle).map(|(coords, _)| coords)
+        }
+
+        /// Iterate over the cells adjacent to `coords` that lie inside the grid,
+        /// as `(direction, coords, value)` triples.
+        fn neighbours(&self, coords: (usize, usize), neighbourhood: Neighbourhood) -> impl Iterator<Item=(Direction, (usize, usize), Self::Value)> {
+            neighbourhood.directions().into_iter().filter_map(move |direction| {
+                let next = self.move_coords(coords, direction).ok()?;
+                Some((direction, next, self.get(next).unwrap()))
+            })
         }
 
-        pub fn get(&self, coords: (usize, usize)) -> Result<T, ()> {
+        /// Count the cells adjacent to `coords` whose values satisfy `predicate`.
+        fn count_neighbours(&self, coords: (usize, usize), neighbourhood: Neighbourhood, predicate: impl Fn(Self::Value) -> bool) -> usize {
+            self.neighbours(coords, neighbourhood).filter(|(_, _, v)| predicate(*v)).count()
+        }
+
+        /// Build a new grid of the same shape by applying `f` to every cell,
+        /// its value and its neighbours (as returned by `neighbours`).
+        fn map_neighbourhood<U: Copy + Debug + Default + PartialEq>(&self, neighbourhood: Neighbourhood, f: impl Fn((usize, usize), Self::Value, &[(Direction, (usize, usize), Self::Value)]) -> U) -> DynGrid<U> {
+            let mut new_grid = DynGrid::create(self.shape());
+            for (coords, v) in self.iter_values_with_coords() {
+                let neighbours: Vec<(Direction, (usize, usize), Self::Value)> = self.neighbours(coords, neighbourhood).collect();
+                new_grid.set(coords, f(coords, v, &neighbours)).unwrap();
+            }
+            new_grid
+        }
+
+        /// Breadth-first search from `start`, only entering cells for which `passable` returns `true`.
+        /// Returns `GridError::OutOfBounds` if `start` is not in the grid.
+        fn bfs(&self, start: (usize, usize), passable: impl Fn((usize, usize), Self::Value) -> bool, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> where Self: Sized {
+            search::bfs(self, start, passable, neighbourhood)
+        }
+
+        /// Dijkstra search from `start`; `cost` returns the cost of entering a cell (`None` = impassable).
+        /// Returns `GridError::OutOfBounds` if `start` is not in the grid.
+        fn dijkstra(&self, start: (usize, usize), cost: impl Fn((usize, usize), Self::Value) -> Option<u64>, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> where Self: Sized {
+            search::dijkstra(self, start, cost, neighbourhood)
+        }
+
+        /// A* search from `start` to `goal`, guided by a `heuristic` that never overestimates the remaining cost.
+        /// The heuristic does not need to be consistent: cells are expanded again if a cheaper route to them is found.
+        /// Returns `GridError::OutOfBounds` if `start` or `goal` is not in the grid.
+        fn astar(&self, start: (usize, usize), goal: (usize, usize), cost: impl Fn((usize, usize), Self::Value) -> Option<u64>, heuristic: impl Fn((usize, usize)) -> u64, neighbourhood: Neighbourhood) -> Result<SearchResult, GridError> where Self: Sized {
+            search::astar(self, start, goal, cost, heuristic, neighbourhood)
+        }
+
+        /// Return all cells connected to `start` through cells satisfying `predicate`
+        /// (empty if `start` itself does not satisfy it).
+        fn flood_fill(&self, start: (usize, usize), predicate: impl Fn(Self::Value) -> bool, neighbourhood: Neighbourhood) -> Vec<(usize, usize)> where Self: Sized {
+            components::flood_fill(self, start, predicate, neighbourhood)
+        }
+
+        /// Label connected regions of cells satisfying `predicate`. Like `flood_fill`, adjacent cells
+        /// connect whenever both satisfy `predicate`, even if their values differ.
+        fn label_components(&self, predicate: impl Fn(Self::Value) -> bool, neighbourhood: Neighbourhood) -> Components where Self: Sized {
+            components::label_components(self, predicate, neighbourhood)
+        }
+
+        /// Label connected regions of cells satisfying `predicate`, where adjacent cells
+        /// only connect if their values are equal (e.g. plots of different crops).
+        fn label_regions(&self, predicate: impl Fn(Self::Value) -> bool, neighbourhood: Neighbourhood) -> Components where Self: Sized {
+            components::label_regions(self, predicate, neighbourhood)
+        }
+
+        /// Borrow the region of the given `shape` with its top-left corner at `offset`.
+        fn view(&self, offset: (usize, usize), shape: (usize, usize)) -> Result<GridView<'_, Self>, GridError> where Self: Sized {
+            GridView::new(self, offset, shape)
+        }
+
+        /// Iterate over all regions of the given shape, in row-major order of their top-left corners.
+        fn windows(&self, shape: (usize, usize)) -> impl Iterator<Item=GridView<'_, Self>> where Self: Sized {
+            GridView::new(self, (0, 0), self.shape()).unwrap().windows(shape)
+        }
+    }
+
+    /// Write access to `Grid`, `DynGrid` and mutable views, on top of `GridLike`.
+    ///
+    /// Implementors only need to provide `set`.
+    pub trait GridLikeMut: GridLike {
+        fn set(&mut self, coords: (usize, usize), v: Self::Value) -> Result<(), GridError>;
+
+        fn set_all(&mut self, v: Self::Value) {
+            let (height, width) = self.shape();
+            for i in 0..height {
+                for j in 0..width {
+                    self.set((i, j), v).unwrap();
+                }
+            }
+        }
+
+        /// Mutably borrow the region of the given `shape` with its top-left corner at `offset`.
+        fn view_mut(&mut self, offset: (usize, usize), shape: (usize, usize)) -> Result<GridViewMut<'_, Self>, GridError> where Self: Sized {
+            GridViewMut::new(self, offset, shape)
+        }
+
+        /// Combine the cells of `other`, placed with its top-left corner at `offset`,
+        /// with the cells of `self` using `merge(self_value, other_value)`.
+        /// Returns an error without changing `self` if `other` does not fit.
+        fn blit<O: GridLike>(&mut self, other: &O, offset: (usize, usize), merge: impl Fn(Self::Value, O::Value) -> Self::Value) -> Result<(), GridError> where Self: Sized {
+            view::blit(self, other, offset, merge)
+        }
+
+        /// Copy `other` into `self` with its top-left corner at `offset`.
+        fn paste<O: GridLike<Value=Self::Value>>(&mut self, other: &O, offset: (usize, usize)) -> Result<(), GridError> where Self: Sized {
+            view::blit(self, other, offset, |_, v| v)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Grid<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> {
+        _grid: [[T; GRID_WIDTH]; GRID_HEIGHT],
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> GridLike for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        type Value = T;
+
+        fn shape(&self) -> (usize, usize) {
+            (GRID_HEIGHT, GRID_WIDTH)
+        }
+
+        fn get(&self, coords: (usize, usize)) -> Result<T, GridError> {
             let (i, j) = coords;
             if self.contains_coords(coords) {
                 Ok(self._grid[i][j])
             } else {
-                Err(())
+                Err(GridError::out_of_bounds(coords, self.shape()))
             }
         }
+    }
 
-        pub fn set(&mut self, coords: (usize, usize), v: T) -> Result<(), ()> {
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> GridLikeMut for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        fn set(&mut self, coords: (usize, usize), v: T) -> Result<(), GridError> {
             let (i, j) = coords;
             if self.contains_coords(coords) {
                 self._grid[i][j] = v;
                 Ok(())
             } else {
-                Err(())
+                Err(GridError::out_of_bounds(coords, self.shape()))
             }
         }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        pub fn create() -> Self {
+            Self { _grid: [[T::default(); GRID_WIDTH]; GRID_HEIGHT] }
+        }
 
         pub fn iter_rows(&self) -> impl Iterator<Item=&[T; GRID_WIDTH]> {
             self._grid.iter()
@@ -63,122 +355,316 @@ pub mod coord_grid {
             self._grid.iter().flat_map(|row| row.iter())
         }
 
-        pub fn iter_coords(&self) -> impl Iterator<Item=(usize, usize)> {
-            (0..GRID_HEIGHT).flat_map(|i| (0..GRID_WIDTH).map(move |j| (i.clone(), j)))
+        /// Return a copy of `self` with `symmetry` applied, as a grid of the transformed shape.
+        fn transformed_into<const NEW_WIDTH: usize, const NEW_HEIGHT: usize>(&self, symmetry: Symmetry) -> Grid<NEW_WIDTH, NEW_HEIGHT, T> {
+            let mut new_grid = Grid::create();
+            self.iter_values_with_coords().for_each(|(coords, v)| {
+                new_grid.set(symmetry.transform_coords(coords, self.shape()), v).unwrap();
+            });
+            new_grid
         }
 
-        pub fn iter_values_with_coords(&self) -> impl Iterator<Item=((usize, usize), T)> {
-            (0..GRID_HEIGHT).flat_map(move |i| (0..GRID_WIDTH).map(move |j| ((i.clone(), j), self._grid[i][j])))
+        /// Return a copy of `self` with `symmetry` applied.
+        /// Panics if the grid is not square and `symmetry` swaps its axes
+        /// (use `rotated_90`, `rotated_270` or `transposed` instead).
+        pub fn transformed(&self, symmetry: Symmetry) -> Self {
+            if GRID_WIDTH != GRID_HEIGHT && symmetry.swaps_axes() {
+                panic!("cannot apply {symmetry:?} to a non-square grid without changing its type")
+            }
+            self.transformed_into(symmetry)
         }
-        
-        pub fn move_coords(&self, coords: (usize, usize), direction: Direction) -> Result<(usize, usize), ()> {
-            match direction {
-                Direction::North => if coords.0 > 0 { Ok((coords.0 - 1, coords.1)) } else { Err(()) },
-                Direction::East => if coords.1 < GRID_WIDTH - 1 { Ok((coords.0, coords.1 + 1)) } else { Err(()) },
-                Direction::South => if coords.0 < GRID_HEIGHT - 1 { Ok((coords.0 + 1, coords.1)) } else { Err(()) },
-                Direction::West => if coords.1 > 0 { Ok((coords.0, coords.1 - 1)) } else { Err(()) },
-                Direction::Northeast => self.move_coords(self.move_coords(coords, Direction::North)?, Direction::East),
-                Direction::Southeast => self.move_coords(self.move_coords(coords, Direction::South)?, Direction::East),
-                Direction::Southwest => self.move_coords(self.move_coords(coords, Direction::South)?, Direction::West),
-                Direction::Northwest => self.move_coords(self.move_coords(coords, Direction::North)?, Direction::West),
+
+        /// Return a clone of `self`, rotated 90° clockwise `n_rot` times.
+        /// Panics for odd `n_rot` if the grid is not square.
+        pub fn rotated(&self, n_rot: usize) -> Self {
+            self.transformed(Symmetry::rotation(n_rot))
+        }
+
+        /// Return a clone of `self`, rotated 90° clockwise.
+        pub fn rotated_90(&self) -> Grid<GRID_HEIGHT, GRID_WIDTH, T> {
+            self.transformed_into(Symmetry::Rotate90)
+        }
+
+        /// Return a clone of `self`, rotated 180° clockwise.
+        pub fn rotated_180(&self) -> Self {
+            self.transformed_into(Symmetry::Rotate180)
+        }
+
+        /// Return a clone of `self`, rotated 270° clockwise.
+        pub fn rotated_270(&self) -> Grid<GRID_HEIGHT, GRID_WIDTH, T> {
+            self.transformed_into(Symmetry::Rotate270)
+        }
+
+        /// Return a clone of `self`, reflected in the main diagonal.
+        pub fn transposed(&self) -> Grid<GRID_HEIGHT, GRID_WIDTH, T> {
+            self.transformed_into(Symmetry::Transpose)
+        }
+
+        /// Return a clone of `self`, mirrored left to right.
+        pub fn flipped_horizontal(&self) -> Self {
+            self.transformed_into(Symmetry::FlipHorizontal)
+        }
+
+        /// Return a clone of `self`, mirrored top to bottom.
+        pub fn flipped_vertical(&self) -> Self {
+            self.transformed_into(Symmetry::FlipVertical)
+        }
+
+        /// Return the distinct grids obtained by applying each `Symmetry` to `self`
+        /// (only those that keep the shape if the grid is not square).
+        pub fn all_orientations(&self) -> Vec<Self> {
+            let mut orientations: Vec<Self> = Vec::new();
+            Symmetry::iter()
+                .filter(|symmetry| GRID_WIDTH == GRID_HEIGHT || !symmetry.swaps_axes())
+                .for_each(|symmetry| {
+                    let new_grid = self.transformed_into(symmetry);
+                    if !orientations.contains(&new_grid) { orientations.push(new_grid); }
+                });
+            orientations
+        }
+
+        pub fn raw(&self) -> &[[T; GRID_WIDTH]; GRID_HEIGHT] {
+            &self._grid
+        }
+
+        pub fn raw_mut(&mut self) -> &mut [[T; GRID_WIDTH]; GRID_HEIGHT] {
+            &mut self._grid
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: GridCell> Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        /// Parse a grid from input lines, mapping each character with `GridCell::from_char`.
+        pub fn from_lines(lines: impl Iterator<Item=io::Result<String>>) -> Result<Self, GridError> {
+            let dyn_grid = parse_cells(lines, Some(GRID_WIDTH))?;
+            let (num_lines, _) = dyn_grid.shape();
+            if num_lines != GRID_HEIGHT {
+                return Err(GridError::WrongLineCount { found: num_lines, expected: GRID_HEIGHT });
+            }
+            dyn_grid.try_into()
+        }
+
+        pub fn parse(reader: impl BufRead) -> Result<Self, GridError> {
+            Self::from_lines(reader.lines())
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: GridCell> FromStr for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        type Err = GridError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::parse(s.as_bytes())
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: GridCell> TryFrom<&str> for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        type Error = GridError;
+
+        fn try_from(s: &str) -> Result<Self, Self::Error> {
+            s.parse()
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: GridCell> fmt::Display for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt_rows(f, self.iter_rows().map(|row| row.as_slice()))
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> TryFrom<DynGrid<T>> for Grid<GRID_WIDTH, GRID_HEIGHT, T> {
+        type Error = GridError;
+
+        fn try_from(dyn_grid: DynGrid<T>) -> Result<Self, Self::Error> {
+            if dyn_grid.shape() != (GRID_HEIGHT, GRID_WIDTH) {
+                return Err(GridError::WrongShape { found: dyn_grid.shape(), expected: (GRID_HEIGHT, GRID_WIDTH) });
             }
+            let mut grid = Self::create();
+            dyn_grid.iter_values_with_coords().for_each(|(coords, v)| grid.set(coords, v).unwrap());
+            Ok(grid)
+        }
+    }
+
+    /// Heap-backed grid whose dimensions are only known at runtime
+    /// (e.g. when they are determined by the puzzle input).
+    /// Values are stored row by row.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct DynGrid<T: Copy + Debug + Default + PartialEq> {
+        _grid: Vec<T>,
+        _height: usize,
+        _width: usize,
+    }
+
+    impl<T: Copy + Debug + Default + PartialEq> GridLike for DynGrid<T> {
+        type Value = T;
+
+        fn shape(&self) -> (usize, usize) {
+            (self._height, self._width)
         }
 
-        pub fn force_move_coords(&self, coords: (usize, usize), direction: Direction) -> (isize, isize) {
-            let coords: (isize, isize) = (coords.0.try_into().unwrap(), coords.1.try_into().unwrap());
-            match direction {
-                Direction::North => (coords.0 - 1, coords.1),
-                Direction::East => (coords.0, coords.1 + 1),
-                Direction::South => (coords.0 + 1, coords.1),
-                Direction::West => (coords.0, coords.1 - 1),
-                Direction::Northeast => (coords.0 - 1, coords.1 + 1),
-                Direction::Southeast => (coords.0 + 1, coords.1 + 1),
-                Direction::Southwest => (coords.0 + 1, coords.1 - 1),
-                Direction::Northwest => (coords.0 - 1, coords.1 - 1),
+        fn get(&self, coords: (usize, usize)) -> Result<T, GridError> {
+            let (i, j) = coords;
+            if self.contains_coords(coords) {
+                Ok(self._grid[i * self._width + j])
+            } else {
+                Err(GridError::out_of_bounds(coords, self.shape()))
             }
         }
+    }
 
-        /// Return a clone of `self`, rotated 90° clockwise `n_rot` times.
-         pub fn rotated(&self, n_rot: usize) -> Self {
-            match n_rot % 4 {
-                0 => self.clone(),
-                1 => self.rotated_90(),
-                2 => self.rotated_180(),
-                3 => self.rotated_270(),
-                _ => unreachable!(),
+    impl<T: Copy + Debug + Default + PartialEq> GridLikeMut for DynGrid<T> {
+        fn set(&mut self, coords: (usize, usize), v: T) -> Result<(), GridError> {
+            let (i, j) = coords;
+            if self.contains_coords(coords) {
+                self._grid[i * self._width + j] = v;
+                Ok(())
+            } else {
+                Err(GridError::out_of_bounds(coords, self.shape()))
             }
         }
 
+        fn set_all(&mut self, v: T) {
+            self._grid.fill(v);
+        }
+    }
+
+    impl<T: Copy + Debug + Default + PartialEq> DynGrid<T> {
+        /// Create a grid with the given `(height, width)`, filled with `T::default()`.
+        pub fn create(shape: (usize, usize)) -> Self {
+            let (height, width) = shape;
+            Self { _grid: vec![T::default(); height * width], _height: height, _width: width }
+        }
+
+        pub fn iter_rows(&self) -> impl Iterator<Item=&[T]> {
+            (0..self._height).map(move |i| &self._grid[(i * self._width)..((i + 1) * self._width)])
+        }
+
+        pub fn iter(&self) -> impl Iterator<Item=&T> {
+            self._grid.iter()
+        }
+
+        /// Return a copy of `self` with `symmetry` applied.
+        pub fn transformed(&self, symmetry: Symmetry) -> Self {
+            let mut new_grid = Self::create(symmetry.transformed_shape(self.shape()));
+            self.iter_values_with_coords().for_each(|(coords, v)| {
+                new_grid.set(symmetry.transform_coords(coords, self.shape()), v).unwrap();
+            });
+            new_grid
+        }
+
+        /// Return a clone of `self`, rotated 90° clockwise `n_rot` times.
+        /// Unlike `Grid::rotated`, this also works for non-square grids.
+        pub fn rotated(&self, n_rot: usize) -> Self {
+            self.transformed(Symmetry::rotation(n_rot))
+        }
+
         /// Return a clone of `self`, rotated 90° clockwise.
         pub fn rotated_90(&self) -> Self {
-            if ! GRID_WIDTH == GRID_HEIGHT { panic!("cannot rotate a non-square grid by 90°") }
-            let mut new_present = Self::create();
-            self.iter_values_with_coords().for_each(|((i, j), v)| {
-                let i_new = j;
-                let j_new = GRID_WIDTH - i - 1;
-                new_present.set((i_new, j_new), v).unwrap();
-            });
-            new_present
+            self.transformed(Symmetry::Rotate90)
         }
 
         /// Return a clone of `self`, rotated 180° clockwise.
         pub fn rotated_180(&self) -> Self {
-            let mut new_present = Grid::create();
-            self.iter_values_with_coords().for_each(|((i, j), v)| {
-                let i_new = GRID_HEIGHT - i - 1;
-                let j_new = GRID_WIDTH - j - 1;
-                new_present.set((i_new, j_new), v).unwrap();
-            });
-            new_present
+            self.transformed(Symmetry::Rotate180)
         }
 
         /// Return a clone of `self`, rotated 270° clockwise.
         pub fn rotated_270(&self) -> Self {
-            if ! GRID_WIDTH == GRID_HEIGHT { panic!("cannot rotate a non-square grid by 270°") }
-            let mut new_present = Grid::create();
-            self.iter_values_with_coords().for_each(|((i, j), v)| {
-                let i_new = GRID_HEIGHT - j - 1;
-                let j_new = i;
-                new_present.set((i_new, j_new), v).unwrap();
+            self.transformed(Symmetry::Rotate270)
+        }
+
+        /// Return a clone of `self`, reflected in the main diagonal.
+        pub fn transposed(&self) -> Self {
+            self.transformed(Symmetry::Transpose)
+        }
+
+        /// Return a clone of `self`, mirrored left to right.
+        pub fn flipped_horizontal(&self) -> Self {
+            self.transformed(Symmetry::FlipHorizontal)
+        }
+
+        /// Return a clone of `self`, mirrored top to bottom.
+        pub fn flipped_vertical(&self) -> Self {
+            self.transformed(Symmetry::FlipVertical)
+        }
+
+        /// Return the distinct grids obtained by applying each `Symmetry` to `self`.
+        pub fn all_orientations(&self) -> Vec<Self> {
+            let mut orientations: Vec<Self> = Vec::new();
+            Symmetry::iter().for_each(|symmetry| {
+                let new_grid = self.transformed(symmetry);
+                if !orientations.contains(&new_grid) { orientations.push(new_grid); }
             });
-            new_present
-        }
-        pub fn position(&self, needle: T) -> Option<(usize, usize)> {
-            for i in 0..GRID_HEIGHT {
-                for j in 0..GRID_WIDTH {
-                    if self.get((i, j)).unwrap() == needle {
-                        return Some((i, j));
-                    }
-                }
-            }
-            None
+            orientations
         }
 
-        pub fn raw(&self) -> &[[T; GRID_WIDTH]; GRID_HEIGHT] {
+        /// Return the underlying values in row-major order.
+        pub fn raw(&self) -> &[T] {
             &self._grid
         }
 
-        pub fn raw_mut(&mut self) -> &mut [[T; GRID_WIDTH]; GRID_HEIGHT] {
+        pub fn raw_mut(&mut self) -> &mut [T] {
             &mut self._grid
         }
     }
 
-    pub fn file_lines_to_char_grid<const GRID_WIDTH: usize, const GRID_HEIGHT: usize>(lines: Lines<BufReader<File>>) -> Result<Grid<GRID_WIDTH, GRID_HEIGHT, char>, String> {
-        let mut grid = Grid::create();
-        let mut num_lines = 0;
-        let mut line_lengths_valid = true;
-        lines.enumerate().for_each(|(i, l)| {
-            num_lines += 1;
-            let line = l.unwrap();
-            if line.len() != GRID_WIDTH { line_lengths_valid = false }
-            line.chars().enumerate().for_each(|(j, c)| { let _ = grid.set((i, j), c); });
-        });
-        if num_lines != GRID_HEIGHT {
-            Err(format!("incorrect number of lines: found {num_lines}, expected {GRID_HEIGHT}"))
-        } else if ! line_lengths_valid {
-            Err(format!("incorrect line length: expected {GRID_WIDTH} characters"))
-        } else {
-            Ok(grid)
+    impl<T: GridCell> DynGrid<T> {
+        /// Parse a grid from input lines, mapping each character with `GridCell::from_char`.
+        /// The grid dimensions are taken from the input itself.
+        pub fn from_lines(lines: impl Iterator<Item=io::Result<String>>) -> Result<Self, GridError> {
+            parse_cells(lines, None)
+        }
+
+        pub fn parse(reader: impl BufRead) -> Result<Self, GridError> {
+            Self::from_lines(reader.lines())
+        }
+    }
+
+    impl<T: GridCell> FromStr for DynGrid<T> {
+        type Err = GridError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Self::parse(s.as_bytes())
+        }
+    }
+
+    impl<T: GridCell> TryFrom<&str> for DynGrid<T> {
+        type Error = GridError;
+
+        fn try_from(s: &str) -> Result<Self, Self::Error> {
+            s.parse()
+        }
+    }
+
+    impl<T: GridCell> fmt::Display for DynGrid<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt_rows(f, self.iter_rows())
+        }
+    }
+
+    impl<const GRID_WIDTH: usize, const GRID_HEIGHT: usize, T: Copy + Debug + Default + PartialEq> From<Grid<GRID_WIDTH, GRID_HEIGHT, T>> for DynGrid<T> {
+        fn from(grid: Grid<GRID_WIDTH, GRID_HEIGHT, T>) -> Self {
+            Self {
+                _grid: grid.iter().copied().collect(),
+                _height: GRID_HEIGHT,
+                _width: GRID_WIDTH,
+            }
+        }
+    }
+
+    /// Set of cells considered adjacent to a cell.
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+    pub enum Neighbourhood {
+        /// The 4 orthogonally adjacent cells
+        VonNeumann,
+        /// All 8 surrounding cells, including diagonals
+        Moore,
+    }
+
+    impl Neighbourhood {
+        pub fn directions(&self) -> Vec<Direction> {
+            match self {
+                Neighbourhood::VonNeumann => Direction::main_directions().to_vec(),
+                Neighbourhood::Moore => Direction::iter().collect(),
+            }
         }
     }
 
@@ -195,6 +681,79 @@ pub mod coord_grid {
     }
     
     impl Direction {
+        /// Offset of one step in this direction.
+        pub fn offset(&self) -> Vec2 {
+            match self {
+                Self::North => Vec2::new(-1, 0),
+                Self::Northeast => Vec2::new(-1, 1),
+                Self::East => Vec2::new(0, 1),
+                Self::Southeast => Vec2::new(1, 1),
+                Self::South => Vec2::new(1, 0),
+                Self::Southwest => Vec2::new(1, -1),
+                Self::West => Vec2::new(0, -1),
+                Self::Northwest => Vec2::new(-1, -1),
+            }
+        }
+
+        /// Parse a direction from a letter (`N`/`E`/`S`/`W` or `U`/`R`/`D`/`L`),
+        /// a caret (`^`, `>`, `v`, `<`) or an arrow (`↑`, `↗`, `→`, ...).
+        pub fn from_char(c: char) -> Option<Self> {
+            match c {
+                'N' | 'U' | '^' | '↑' => Some(Self::North),
+                '↗' => Some(Self::Northeast),
+                'E' | 'R' | '>' | '→' => Some(Self::East),
+                '↘' => Some(Self::Southeast),
+                'S' | 'D' | 'v' | '↓' => Some(Self::South),
+                '↙' => Some(Self::Southwest),
+                'W' | 'L' | '<' | '←' => Some(Self::West),
+                '↖' => Some(Self::Northwest),
+                _ => None,
+            }
+        }
+
+        /// Return the arrow character pointing in this direction.
+        pub fn to_arrow(&self) -> char {
+            match self {
+                Self::North => '↑',
+                Self::Northeast => '↗',
+                Self::East => '→',
+                Self::Southeast => '↘',
+                Self::South => '↓',
+                Self::Southwest => '↙',
+                Self::West => '←',
+                Self::Northwest => '↖',
+            }
+        }
+
+        pub fn opposite(&self) -> Self {
+            self.turn_90_clockwise().turn_90_clockwise()
+        }
+
+        pub fn is_diagonal(&self) -> bool {
+            matches!(self, Self::Northeast | Self::Southeast | Self::Southwest | Self::Northwest)
+        }
+
+        pub fn turn(&self, turn: Turn) -> Self {
+            match turn {
+                Turn::Left => self.turn_90_anticlockwise(),
+                Turn::Right => self.turn_90_clockwise(),
+                Turn::Around => self.opposite(),
+            }
+        }
+
+        pub fn turn_45_clockwise(&self) -> Self {
+            match self {
+                Self::North => Self::Northeast,
+                Self::Northeast => Self::East,
+                Self::East => Self::Southeast,
+                Self::Southeast => Self::South,
+                Self::South => Self::Southwest,
+                Self::Southwest => Self::West,
+                Self::West => Self::Northwest,
+                Self::Northwest => Self::North,
+            }
+        }
+
         pub fn turn_45_anticlockwise(&self) -> Self {
             match self {
                 Self::North => Self::Northwest,
@@ -238,15 +797,810 @@ pub mod coord_grid {
             [Self::North, Self::East, Self::South, Self::West]
         }
 
+        pub fn diagonals() -> [Self; 4] {
+            [Self::Northeast, Self::Southeast, Self::Southwest, Self::Northwest]
+        }
+
+        pub fn turn_clockwise(&self, degrees: usize) -> Result<Self, &str> {
+            if !degrees.is_multiple_of(45) {
+                Err("invalid turn angle - must be multiple of 45")
+            } else {
+                let num_45deg_turns = (degrees / 45) % 8;
+                let mut new_dir = *self;
+                for _ in 0..num_45deg_turns { new_dir = new_dir.turn_45_clockwise(); }
+                Ok(new_dir)
+            }
+        }
+
         pub fn turn_anticlockwise(&self, degrees: usize) -> Result<Self, &str> {
-            if degrees % 45 != 0 {
+            if !degrees.is_multiple_of(45) {
                 Err("invalid turn angle - must be multiple of 45")
             } else {
-                let num_45deg_turns = degrees / 45;
-                let mut new_dir = self.clone();
+                let num_45deg_turns = (degrees / 45) % 8;
+                let mut new_dir = *self;
                 for _ in 0..num_45deg_turns { new_dir = new_dir.turn_45_anticlockwise(); }
                 Ok(new_dir)
             }
         }
     }
+
+    /// Turn relative to the current direction.
+    #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+    pub enum Turn {
+        /// 90° anticlockwise
+        Left,
+        /// 90° clockwise
+        Right,
+        /// 180°
+        Around,
+    }
+
+    impl Turn {
+        /// Parse `L`, `R` or `U` (U-turn).
+        pub fn from_char(c: char) -> Option<Self> {
+            match c {
+                'L' => Some(Self::Left),
+                'R' => Some(Self::Right),
+                'U' => Some(Self::Around),
+                _ => None,
+            }
+        }
+
+        /// Return the turn in the opposite sense.
+        pub fn reversed(&self) -> Self {
+            match self {
+                Self::Left => Self::Right,
+                Self::Right => Self::Left,
+                Self::Around => Self::Around,
+            }
+        }
+    }
+}
+
+/// Locating and reading puzzle input.
+pub mod input {
+    use std::error::Error;
+    use std::fmt;
+    use std::fs;
+    use std::io;
+    use std::io::Read;
+    use std::path::{Path, PathBuf};
+
+    /// Directory containing the puzzle inputs (`input/` in the crate root,
+    /// independent of the working directory).
+    pub fn input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+    }
+
+    /// Where the input for a puzzle is read from.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum InputSource {
+        /// The real input, `input/day_XX.txt`
+        Day(u8),
+        /// The example from the puzzle description, `input/day_XX_example.txt`
+        Example(u8),
+        Path(PathBuf),
+        Stdin,
+    }
+
+    impl InputSource {
+        /// Return the file read by this source (`None` for stdin).
+        pub fn path(&self) -> Option<PathBuf> {
+            match self {
+                InputSource::Day(day) => Some(input_dir().join(format!("day_{day:02}.txt"))),
+                InputSource::Example(day) => Some(input_dir().join(format!("day_{day:02}_example.txt"))),
+                InputSource::Path(path) => Some(path.clone()),
+                InputSource::Stdin => None,
+            }
+        }
+
+        /// Short name identifying this input in the answer store: `input` or `example`
+        /// (`None` for other files and stdin, see `canonical`).
+        pub fn label(&self) -> Option<String> {
+            match self {
+                InputSource::Day(_) => Some("input".to_string()),
+                InputSource::Example(_) => Some("example".to_string()),
+                InputSource::Path(_) | InputSource::Stdin => None,
+            }
+        }
+
+        /// Return `Day(day)` or `Example(day)` if `self` is a path to that file, otherwise a copy of `self`.
+        pub fn canonical(&self, day: u8) -> InputSource {
+            let InputSource::Path(path) = self else { return self.clone() };
+            let Ok(path) = fs::canonicalize(path) else { return self.clone() };
+            [InputSource::Day(day), InputSource::Example(day)].into_iter()
+                .find(|source| source.path().and_then(|p| fs::canonicalize(p).ok()).as_ref() == Some(&path))
+                .unwrap_or_else(|| self.clone())
+        }
+
+        /// Read the whole input into a string.
+        pub fn read(&self) -> Result<String, InputError> {
+            match self.path() {
+                Some(path) => fs::read_to_string(&path).map_err(|e| match e.kind() {
+                    io::ErrorKind::NotFound => InputError::NotFound(self.clone()),
+                    _ => InputError::Io(self.clone(), e),
+                }),
+                None => {
+                    let mut input = String::new();
+                    io::stdin().read_to_string(&mut input).map_err(|e| InputError::Io(self.clone(), e))?;
+                    Ok(input)
+                },
+            }
+        }
+    }
+
+    impl fmt::Display for InputSource {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.path() {
+                Some(path) => write!(f, "{}", path.display()),
+                None => write!(f, "stdin"),
+            }
+        }
+    }
+
+    /// Errors raised while reading puzzle input.
+    #[derive(Debug)]
+    pub enum InputError {
+        NotFound(InputSource),
+        Io(InputSource, io::Error),
+    }
+
+    impl fmt::Display for InputError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                InputError::NotFound(source @ InputSource::Day(_)) => write!(f, "input file {source} not found - save your puzzle input there or pass --input <path>"),
+                InputError::NotFound(source @ InputSource::Example(_)) => write!(f, "example input {source} not found - save the example from the puzzle description there"),
+                InputError::NotFound(source) => write!(f, "input file {source} not found"),
+                InputError::Io(source, e) => write!(f, "unable to read input from {source}: {e}"),
+            }
+        }
+    }
+
+    impl Error for InputError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                InputError::Io(_, e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Recorded correct answers, stored in `input/answers.toml` as
+/// ```toml
+/// [day_01.part_1]
+/// input = "1234"
+/// example = "3"
+/// ```
+pub mod answers {
+    use std::error::Error;
+    use std::fmt;
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+    use toml::{Table, Value};
+    use crate::input::input_dir;
+
+    /// Default location of the answer store.
+    pub fn answers_path() -> PathBuf {
+        input_dir().join("answers.toml")
+    }
+
+    /// Answers keyed by day, part and input label (see `InputSource::label`).
+    #[derive(Debug, Clone)]
+    pub struct AnswerStore {
+        path: PathBuf,
+        table: Table,
+    }
+
+    impl AnswerStore {
+        /// Load the store from `path`, starting empty if the file does not exist.
+        pub fn load(path: PathBuf) -> Result<Self, AnswerError> {
+            let table = match fs::read_to_string(&path) {
+                Ok(content) => content.parse::<Table>().map_err(|e| AnswerError::Parse(path.clone(), e))?,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Table::new(),
+                Err(e) => return Err(AnswerError::Io(path, e)),
+            };
+            Ok(Self { path, table })
+        }
+
+        /// Load the store from `input/answers.toml`.
+        pub fn load_default() -> Result<Self, AnswerError> {
+            Self::load(answers_path())
+        }
+
+        pub fn path(&self) -> &PathBuf {
+            &self.path
+        }
+
+        fn keys(day: u8, part: u8) -> (String, String) {
+            (format!("day_{day:02}"), format!("part_{part}"))
+        }
+
+        /// Return the recorded answer, if any.
+        pub fn get(&self, day: u8, part: u8, label: &str) -> Option<&str> {
+            let (day_key, part_key) = Self::keys(day, part);
+            self.table.get(&day_key)?.get(&part_key)?.get(label)?.as_str()
+        }
+
+        /// Record an answer, replacing any previous one. Returns the previous answer,
+        /// or `AnswerError::Malformed` if the day or part entry in the file is not a table.
+        pub fn record(&mut self, day: u8, part: u8, label: &str, answer: &str) -> Result<Option<String>, AnswerError> {
+            let (day_key, part_key) = Self::keys(day, part);
+            let malformed = |key: String| AnswerError::Malformed(self.path.clone(), key);
+            let day_table = self.table.entry(day_key.clone()).or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut().ok_or_else(|| malformed(day_key.clone()))?;
+            let part_table = day_table.entry(part_key.clone()).or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut().ok_or_else(|| malformed(format!("{day_key}.{part_key}")))?;
+            let previous = part_table.insert(label.to_string(), Value::String(answer.to_string()));
+            Ok(previous.and_then(|value| value.as_str().map(str::to_string)))
+        }
+
+        /// Write the store back to its file.
+        pub fn save(&self) -> Result<(), AnswerError> {
+            fs::write(&self.path, self.table.to_string()).map_err(|e| AnswerError::Io(self.path.clone(), e))
+        }
+    }
+
+    /// Errors raised while loading or saving the answer store.
+    #[derive(Debug)]
+    pub enum AnswerError {
+        Io(PathBuf, io::Error),
+        Parse(PathBuf, toml::de::Error),
+        /// The entry with the given key is not a table.
+        Malformed(PathBuf, String),
+    }
+
+    impl fmt::Display for AnswerError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                AnswerError::Io(path, e) => write!(f, "unable to access answer store {}: {e}", path.display()),
+                AnswerError::Parse(path, e) => write!(f, "invalid answer store {}: {e}", path.display()),
+                AnswerError::Malformed(path, key) => write!(f, "invalid answer store {}: `{key}` is not a table", path.display()),
+            }
+        }
+    }
+
+    impl Error for AnswerError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                AnswerError::Io(_, e) => Some(e),
+                AnswerError::Parse(_, e) => Some(e),
+                AnswerError::Malformed(..) => None,
+            }
+        }
+    }
+}
+
+/// Diagnostic output of the solutions, written with the `log` macros.
+/// Nothing below warnings is shown unless enabled, globally or for single days,
+/// through `init` (the runner's `-v`, `-q`, `--log` and `--log-file` options).
+pub mod logging {
+    use std::error::Error;
+    use std::fmt;
+    use std::fs::File;
+    use std::io;
+    use std::io::{BufWriter, Write};
+    use std::path::PathBuf;
+    use std::str::FromStr;
+    use std::sync::Mutex;
+    use log::{LevelFilter, Log, Metadata, Record};
+
+    /// Level for the messages of one day, or one part of it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DayFilter {
+        pub day: u8,
+        pub part: Option<u8>,
+        pub level: LevelFilter,
+    }
+
+    impl DayFilter {
+        /// Check whether the filter applies to messages of the given day and part.
+        pub fn matches(&self, day: u8, part: u8) -> bool {
+            self.day == day && self.part.is_none_or(|p| p == part)
+        }
+    }
+
+    /// Parse `<day>[.<part>]=<level>`, e.g. `2=trace` or `11.2=debug`.
+    impl FromStr for DayFilter {
+        type Err = LogError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let invalid = || LogError::InvalidFilter(s.to_string());
+            let (selector, level) = s.split_once('=').ok_or_else(invalid)?;
+            let (day, part) = match selector.split_once('.') {
+                Some((day, part)) => (day, Some(part.parse().map_err(|_| invalid())?)),
+                None => (selector, None),
+            };
+            Ok(Self { day: day.parse().map_err(|_| invalid())?, part, level: level.parse().map_err(|_| invalid())? })
+        }
+    }
+
+    /// Which messages are written, and where.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct LogConfig {
+        /// Level for all messages not matched by a day filter
+        pub level: LevelFilter,
+        /// Overrides for single days; later filters take precedence
+        pub day_filters: Vec<DayFilter>,
+        /// File receiving the messages instead of stderr
+        pub file: Option<PathBuf>,
+    }
+
+    impl Default for LogConfig {
+        fn default() -> Self {
+            Self { level: LevelFilter::Warn, day_filters: Vec::new(), file: None }
+        }
+    }
+
+    impl LogConfig {
+        /// Take the logging options (`-v`, `-vv`, `-vvv`, `-q`, `--log <filters>` and `--log-file <path>`)
+        /// out of the command line `args`, returning the configuration and the remaining arguments.
+        pub fn from_args(args: Vec<String>) -> Result<(Self, Vec<String>), LogError> {
+            let mut config = Self::default();
+            let mut verbosity = 0;
+            let mut quiet = false;
+            let mut other_args = Vec::new();
+            let mut args_iter = args.into_iter();
+            while let Some(arg) = args_iter.next() {
+                match arg.as_str() {
+                    "-q" | "--quiet" => quiet = true,
+                    "--log" => {
+                        let filters = args_iter.next().ok_or(LogError::MissingValue(arg))?;
+                        for filter in filters.split(',') {
+                            config.day_filters.push(filter.parse::<DayFilter>()?);
+                        }
+                    },
+                    "--log-file" => config.file = Some(PathBuf::from(args_iter.next().ok_or(LogError::MissingValue(arg))?)),
+                    _ if arg.len() > 1 && arg.strip_prefix('-').is_some_and(|flags| flags.chars().all(|c| c == 'v')) => verbosity += arg.len() - 1,
+                    _ => other_args.push(arg),
+                }
+            }
+            config.level = match (quiet, verbosity) {
+                (true, 0) => LevelFilter::Off,
+                (true, _) => return Err(LogError::QuietAndVerbose),
+                (false, 0) => LevelFilter::Warn,
+                (false, 1) => LevelFilter::Info,
+                (false, 2) => LevelFilter::Debug,
+                (false, _) => LevelFilter::Trace,
+            };
+            Ok((config, other_args))
+        }
+
+        /// Level for messages of the module `target`, e.g. `aoc_2025_rust::days::day_02_1`.
+        pub fn level_for(&self, target: &str) -> LevelFilter {
+            day_part(target)
+                .and_then(|(day, part)| self.day_filters.iter().rev().find(|filter| filter.matches(day, part)))
+                .map_or(self.level, |filter| filter.level)
+        }
+
+        /// Highest level enabled for any module.
+        pub fn max_level(&self) -> LevelFilter {
+            self.day_filters.iter().map(|filter| filter.level).fold(self.level, Ord::max)
+        }
+    }
+
+    /// Extract day and part from a module path such as `aoc_2025_rust::days::day_02_1`.
+    fn day_part(target: &str) -> Option<(u8, u8)> {
+        let module = target.rsplit("::").next()?.strip_prefix("day_")?;
+        let (day, part) = module.split_once('_')?;
+        Some((day.parse().ok()?, part.parse().ok()?))
+    }
+
+    struct Logger {
+        config: LogConfig,
+        output: Mutex<Box<dyn Write + Send>>,
+    }
+
+    impl Log for Logger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= self.config.level_for(metadata.target())
+        }
+
+        fn log(&self, record: &Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            let source = match day_part(record.target()) {
+                Some((day, part)) => format!("day {day} part {part}"),
+                None => record.target().to_string(),
+            };
+            let mut output = self.output.lock().unwrap();
+            // a failing log output must not abort the solution
+            let _ = writeln!(output, "[{} {source}] {}", record.level(), record.args());
+        }
+
+        fn flush(&self) {
+            let _ = self.output.lock().unwrap().flush();
+        }
+    }
+
+    /// Install the logger. Can only be called once.
+    pub fn init(config: LogConfig) -> Result<(), LogError> {
+        let output: Box<dyn Write + Send> = match &config.file {
+            Some(path) => Box::new(BufWriter::new(File::create(path).map_err(|e| LogError::Io(path.clone(), e))?)),
+            None => Box::new(io::stderr()),
+        };
+        let max_level = config.max_level();
+        log::set_boxed_logger(Box::new(Logger { config, output: Mutex::new(output) })).map_err(|_| LogError::AlreadyInitialised)?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    /// Errors raised while configuring logging.
+    #[derive(Debug)]
+    pub enum LogError {
+        InvalidFilter(String),
+        /// The option was not followed by its value.
+        MissingValue(String),
+        QuietAndVerbose,
+        Io(PathBuf, io::Error),
+        AlreadyInitialised,
+    }
+
+    impl fmt::Display for LogError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                LogError::InvalidFilter(filter) => write!(f, "invalid log filter: {filter} (expected <day>[.<part>]=<level>)"),
+                LogError::MissingValue(option) => write!(f, "missing value after {option}"),
+                LogError::QuietAndVerbose => write!(f, "-q and -v cannot be combined"),
+                LogError::Io(path, e) => write!(f, "unable to open log file {}: {e}", path.display()),
+                LogError::AlreadyInitialised => write!(f, "logging is already initialised"),
+            }
+        }
+    }
+
+    impl Error for LogError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                LogError::Io(_, e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Evaluation of independent puzzle records (lines, ranges, machines, ...),
+/// spread over all cores if the `parallel` feature is enabled.
+pub mod parallel {
+    /// Apply `f` to every record, returning the results in the same order.
+    pub fn map_records<T: Sync, U: Send>(records: &[T], f: impl Fn(&T) -> U + Sync + Send) -> Vec<U> {
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            records.par_iter().map(f).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        records.iter().map(f).collect()
+    }
+}
+
+/// Time spent parsing input, as opposed to solving, while running a solution.
+/// Solutions wrap their parsing step in `timing::parse`; `measure` then splits
+/// the total time of a run into both phases.
+pub mod timing {
+    use std::cell::Cell;
+    use std::time::{Duration, Instant};
+
+    thread_local! {
+        static PARSE_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    }
+
+    /// Time taken by a single run of a solution.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct Timings {
+        pub parse: Duration,
+        pub solve: Duration,
+    }
+
+    impl Timings {
+        pub fn total(&self) -> Duration {
+            self.parse + self.solve
+        }
+    }
+
+    /// Run the parsing step `f`, adding the time taken to the current measurement.
+    pub fn parse<T>(f: impl FnOnce() -> T) -> T {
+        let now = Instant::now();
+        let result = f();
+        PARSE_TIME.with(|time| time.set(time.get() + now.elapsed()));
+        result
+    }
+
+    /// Run `f`, returning its result and the time spent parsing and solving.
+    /// Everything not wrapped in `parse` counts as solving.
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Timings) {
+        PARSE_TIME.with(|time| time.set(Duration::ZERO));
+        let now = Instant::now();
+        let result = f();
+        let total = now.elapsed();
+        let parse = PARSE_TIME.with(|time| time.replace(Duration::ZERO)).min(total);
+        (result, Timings { parse, solve: total - parse })
+    }
+}
+
+pub mod solution {
+    use std::error::Error;
+    use std::fmt;
+
+    /// Result of a puzzle part, as reported by the runner.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Answer {
+        Unsigned(u64),
+        Signed(i64),
+        Text(String),
+    }
+
+    impl fmt::Display for Answer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Answer::Unsigned(n) => write!(f, "{n}"),
+                Answer::Signed(n) => write!(f, "{n}"),
+                Answer::Text(s) => write!(f, "{s}"),
+            }
+        }
+    }
+
+    impl From<usize> for Answer {
+        fn from(n: usize) -> Self {
+            Answer::Unsigned(n.try_into().expect("answer too large"))
+        }
+    }
+
+    impl From<u64> for Answer {
+        fn from(n: u64) -> Self {
+            Answer::Unsigned(n)
+        }
+    }
+
+    impl From<i64> for Answer {
+        fn from(n: i64) -> Self {
+            Answer::Signed(n)
+        }
+    }
+
+    impl From<String> for Answer {
+        fn from(s: String) -> Self {
+            Answer::Text(s)
+        }
+    }
+
+    /// One part of one day's puzzle.
+    pub trait Solution {
+        fn day(&self) -> u8;
+
+        fn part(&self) -> u8;
+
+        /// Label printed in front of the answer.
+        fn output_message(&self) -> &str {
+            "Total"
+        }
+
+        fn solve(&self, input: &str) -> Result<Answer, Box<dyn Error>>;
+    }
+}
+
+/// Repeated timing of solutions, with reports saved as CSV so that runs can be compared.
+pub mod bench {
+    use std::error::Error;
+    use std::fmt;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+    use crate::solution::{Answer, Solution};
+    use crate::timing::{measure, Timings};
+
+    const CSV_HEADER: &str = "day,part,runs,parse_min_us,parse_median_us,parse_max_us,solve_min_us,solve_median_us,solve_max_us,total_min_us,total_median_us,total_max_us";
+
+    /// Minimum, median and maximum of a set of durations.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Stats {
+        pub min: Duration,
+        pub median: Duration,
+        pub max: Duration,
+    }
+
+    impl Stats {
+        /// Panics if `samples` is empty.
+        pub fn from_samples(samples: impl IntoIterator<Item=Duration>) -> Self {
+            let mut sorted: Vec<Duration> = samples.into_iter().collect();
+            sorted.sort_unstable();
+            Self { min: sorted[0], median: sorted[sorted.len() / 2], max: sorted[sorted.len() - 1] }
+        }
+    }
+
+    impl fmt::Display for Stats {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:?} / {:?} / {:?}", self.min, self.median, self.max)
+        }
+    }
+
+    /// Timings of one solution over several runs.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BenchResult {
+        pub day: u8,
+        pub part: u8,
+        pub runs: usize,
+        pub parse: Stats,
+        pub solve: Stats,
+        pub total: Stats,
+    }
+
+    /// Run `solution` on `input` `runs` times (at least once), returning the answer of the last run and the timings.
+    pub fn bench(solution: &dyn Solution, input: &str, runs: usize) -> Result<(Answer, BenchResult), Box<dyn Error>> {
+        let mut samples: Vec<Timings> = Vec::new();
+        let mut answer = None;
+        for _ in 0..runs.max(1) {
+            let (result, timings) = measure(|| solution.solve(input));
+            answer = Some(result?);
+            samples.push(timings);
+        }
+        let result = BenchResult {
+            day: solution.day(),
+            part: solution.part(),
+            runs: samples.len(),
+            parse: Stats::from_samples(samples.iter().map(|t| t.parse)),
+            solve: Stats::from_samples(samples.iter().map(|t| t.solve)),
+            total: Stats::from_samples(samples.iter().map(|t| t.total())),
+        };
+        Ok((answer.unwrap(), result))
+    }
+
+    /// A solution whose median total time grew by more than the allowed fraction.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Regression {
+        pub day: u8,
+        pub part: u8,
+        pub previous: Duration,
+        pub current: Duration,
+    }
+
+    impl fmt::Display for Regression {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let change = 100.0 * (self.current.as_secs_f64() / self.previous.as_secs_f64() - 1.0);
+            write!(f, "day {} part {}: median {:?} -> {:?} (+{change:.0}%)", self.day, self.part, self.previous, self.current)
+        }
+    }
+
+    /// Compare median total times with a previous report, returning the solutions
+    /// that got slower by more than `threshold` (e.g. `0.1` for 10%).
+    /// Solutions missing from either report are ignored.
+    pub fn compare(previous: &[BenchResult], current: &[BenchResult], threshold: f64) -> Vec<Regression> {
+        current.iter().filter_map(|result| {
+            let old = previous.iter().find(|old| old.day == result.day && old.part == result.part)?;
+            let (before, after) = (old.total.median, result.total.median);
+            (after.as_secs_f64() > before.as_secs_f64() * (1.0 + threshold))
+                .then_some(Regression { day: result.day, part: result.part, previous: before, current: after })
+        }).collect()
+    }
+
+    /// Write a report as CSV, with all durations in microseconds.
+    pub fn save_report(path: &Path, results: &[BenchResult]) -> Result<(), ReportError> {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for result in results {
+            let stats = [result.parse, result.solve, result.total];
+            let durations = stats.iter().flat_map(|s| [s.min, s.median, s.max]).map(|d| d.as_micros().to_string());
+            let fields: Vec<String> = [result.day.to_string(), result.part.to_string(), result.runs.to_string()].into_iter().chain(durations).collect();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        fs::write(path, csv).map_err(|e| ReportError::Io(path.to_path_buf(), e))
+    }
+
+    /// Read a report written by `save_report`.
+    pub fn load_report(path: &Path) -> Result<Vec<BenchResult>, ReportError> {
+        let csv = fs::read_to_string(path).map_err(|e| ReportError::Io(path.to_path_buf(), e))?;
+        let invalid = |line: usize| ReportError::Invalid(path.to_path_buf(), line);
+        let mut lines = csv.lines().enumerate();
+        if lines.next().is_none_or(|(_, header)| header.trim() != CSV_HEADER) {
+            return Err(invalid(1));
+        }
+        lines.filter(|(_, line)| !line.trim().is_empty()).map(|(i, line)| {
+            let fields: Vec<u64> = line.split(',').map(|field| field.trim().parse::<u64>()).collect::<Result<_, _>>().map_err(|_| invalid(i + 1))?;
+            if fields.len() != CSV_HEADER.split(',').count() {
+                return Err(invalid(i + 1));
+            }
+            let stats = |k: usize| Stats {
+                min: Duration::from_micros(fields[k]),
+                median: Duration::from_micros(fields[k + 1]),
+                max: Duration::from_micros(fields[k + 2]),
+            };
+            let day = fields[0].try_into().map_err(|_| invalid(i + 1))?;
+            let part = fields[1].try_into().map_err(|_| invalid(i + 1))?;
+            Ok(BenchResult { day, part, runs: fields[2] as usize, parse: stats(3), solve: stats(6), total: stats(9) })
+        }).collect()
+    }
+
+    /// Errors raised while reading or writing benchmark reports.
+    #[derive(Debug)]
+    pub enum ReportError {
+        Io(PathBuf, io::Error),
+        /// Line number of the malformed line
+        Invalid(PathBuf, usize),
+    }
+
+    impl fmt::Display for ReportError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ReportError::Io(path, e) => write!(f, "unable to access benchmark report {}: {e}", path.display()),
+                ReportError::Invalid(path, line) => write!(f, "invalid benchmark report {} (line {line})", path.display()),
+            }
+        }
+    }
+
+    impl Error for ReportError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                ReportError::Io(_, e) => Some(e),
+                ReportError::Invalid(..) => None,
+            }
+        }
+    }
+}
+
+pub mod days {
+    use crate::solution::Solution;
+
+    pub mod dial;
+    pub mod dial_script;
+    pub mod digit_selection;
+    pub mod repeated_ids;
+    pub mod day_01_1;
+    pub mod day_01_2;
+    pub mod day_02_1;
+    pub mod day_02_2;
+    pub mod day_03_1;
+    pub mod day_03_2;
+    pub mod day_04_1;
+    pub mod day_04_2;
+    pub mod day_05_1;
+    pub mod day_05_2;
+    pub mod day_06_1;
+    pub mod day_06_2;
+    pub mod day_07_1;
+    pub mod day_07_2;
+    pub mod day_08_1;
+    pub mod day_08_2;
+    pub mod day_09_1;
+    pub mod day_09_2;
+    pub mod day_10_1;
+    pub mod day_10_2;
+    pub mod day_11_1;
+    pub mod day_11_2;
+    pub mod day_12_1;
+
+    /// Registry of all implemented solutions, ordered by day and part.
+    pub fn all() -> Vec<Box<dyn Solution>> {
+        vec![
+            Box::new(day_01_1::Day01Part1),
+            Box::new(day_01_2::Day01Part2),
+            Box::new(day_02_1::Day02Part1),
+            Box::new(day_02_2::Day02Part2),
+            Box::new(day_03_1::Day03Part1),
+            Box::new(day_03_2::Day03Part2),
+            Box::new(day_04_1::Day04Part1),
+            Box::new(day_04_2::Day04Part2),
+            Box::new(day_05_1::Day05Part1),
+            Box::new(day_05_2::Day05Part2),
+            Box::new(day_06_1::Day06Part1),
+            Box::new(day_06_2::Day06Part2),
+            Box::new(day_07_1::Day07Part1),
+            Box::new(day_07_2::Day07Part2),
+            Box::new(day_08_1::Day08Part1),
+            Box::new(day_08_2::Day08Part2),
+            Box::new(day_09_1::Day09Part1),
+            Box::new(day_09_2::Day09Part2),
+            Box::new(day_10_1::Day10Part1),
+            Box::new(day_10_2::Day10Part2),
+            Box::new(day_11_1::Day11Part1),
+            Box::new(day_11_2::Day11Part2),
+            Box::new(day_12_1::Day12Part1),
+        ]
+    }
+
+    /// Look up the solution for a single day and part.
+    pub fn find(day: u8, part: u8) -> Option<Box<dyn Solution>> {
+        all().into_iter().find(|solution| solution.day() == day && solution.part() == part)
+    }
 }
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::PathBuf;
+use aoc_2025_rust::answers::{AnswerError, AnswerStore};
+
+/// Path of a file in the temp directory unique to this test process.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc_answers_{}_{name}.toml", std::process::id()))
+}
+
+#[test]
+fn missing_store_starts_empty() {
+    let path = temp_path("missing");
+    let store = AnswerStore::load(path.clone()).unwrap();
+    assert_eq!(store.get(1, 1, "input"), None);
+    assert!(!path.exists());
+}
+
+#[test]
+fn recorded_answers_survive_saving() {
+    let path = temp_path("round_trip");
+    let mut store = AnswerStore::load(path.clone()).unwrap();
+    assert_eq!(store.record(1, 1, "input", "1234").unwrap(), None);
+    assert_eq!(store.record(1, 1, "example", "3").unwrap(), None);
+    assert_eq!(store.record(1, 1, "input", "1235").unwrap(), Some("1234".to_string()));
+    store.save().unwrap();
+    let loaded = AnswerStore::load(path.clone()).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.get(1, 1, "input"), Some("1235"));
+    assert_eq!(loaded.get(1, 1, "example"), Some("3"));
+    assert_eq!(loaded.get(1, 2, "input"), None);
+    assert_eq!(loaded.get(2, 1, "input"), None);
+}
+
+#[test]
+fn hand_written_store_is_read() {
+    let path = temp_path("hand_written");
+    fs::write(&path, "[day_03.part_2]\ninput = \"987\"\n").unwrap();
+    let store = AnswerStore::load(path.clone()).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(store.get(3, 2, "input"), Some("987"));
+}
+
+#[test]
+fn invalid_store_fails_to_load() {
+    let path = temp_path("invalid");
+    fs::write(&path, "[day_01\n").unwrap();
+    let result = AnswerStore::load(path.clone());
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(AnswerError::Parse(..))));
+}
+
+#[test]
+fn recording_into_malformed_store_fails() {
+    let path = temp_path("malformed");
+    fs::write(&path, "day_01 = 5\n[day_02]\npart_1 = \"x\"\n").unwrap();
+    let mut store = AnswerStore::load(path.clone()).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(store.record(1, 1, "input", "1"), Err(AnswerError::Malformed(_, key)) if key == "day_01"));
+    assert!(matches!(store.record(2, 1, "input", "1"), Err(AnswerError::Malformed(_, key)) if key == "day_02.part_1"));
+    assert_eq!(store.get(2, 1, "input"), None);
+    assert_eq!(store.record(2, 2, "input", "1").unwrap(), None);
+}
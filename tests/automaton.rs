@@ -0,0 +1,60 @@
+use aoc_2025_rust::coord_grid::{Automaton, DynGrid, GridLike, GridLikeMut, Neighbourhood, RunOutcome, UpdateMode};
+
+/// Conway's game of life.
+fn life(grid: &DynGrid<bool>, coords: (usize, usize)) -> bool {
+    let alive = grid.get(coords).unwrap();
+    matches!((alive, grid.count_neighbours(coords, Neighbourhood::Moore, |v| v)), (true, 2) | (_, 3))
+}
+
+fn cells(rows: &[&str]) -> DynGrid<bool> {
+    let mut grid = DynGrid::create((rows.len(), rows[0].len()));
+    for (i, row) in rows.iter().enumerate() {
+        for (j, c) in row.chars().enumerate() {
+            grid.set((i, j), c == '#').unwrap();
+        }
+    }
+    grid
+}
+
+#[test]
+fn still_life_is_a_fixpoint() {
+    let block = cells(&["....", ".##.", ".##.", "...."]);
+    let mut automaton = Automaton::new(block.clone(), life);
+    assert_eq!(automaton.run(Some(10)), RunOutcome::Fixpoint { generation: 0 });
+    assert_eq!(automaton.grid(), &block);
+}
+
+#[test]
+fn blinker_cycles_with_length_2() {
+    let blinker = cells(&[".....", "..#..", "..#..", "..#..", "....."]);
+    let mut automaton = Automaton::new(blinker.clone(), life);
+    assert_eq!(automaton.run(None), RunOutcome::Cycle { start: 0, length: 2 });
+    assert_eq!(automaton.generation(), 2);
+    assert_eq!(automaton.grid(), &blinker);
+    assert_eq!(automaton.change_counts(), [4, 4]);
+}
+
+#[test]
+fn generation_limit_stops_run() {
+    let glider = cells(&[".#......", "..#.....", "###.....", "........", "........", "........"]);
+    let mut automaton = Automaton::new(glider, life);
+    assert_eq!(automaton.run(Some(3)), RunOutcome::GenerationLimit);
+    assert_eq!(automaton.generation(), 3);
+}
+
+/// A cell becomes set when the cell to its left is set.
+fn spread_right(grid: &DynGrid<bool>, (i, j): (usize, usize)) -> bool {
+    grid.get((i, j)).unwrap() || (j > 0 && grid.get((i, j - 1)).unwrap())
+}
+
+#[test]
+fn in_place_updates_see_earlier_cells() {
+    let row = cells(&["#...."]);
+    let mut synchronous = Automaton::new(row.clone(), spread_right);
+    assert_eq!(synchronous.step(), 1);
+    assert_eq!(synchronous.grid(), &cells(&["##..."]));
+    let mut in_place = Automaton::new(row, spread_right).mode(UpdateMode::InPlace);
+    assert_eq!(in_place.step(), 4);
+    assert_eq!(in_place.grid(), &cells(&["#####"]));
+    assert_eq!(in_place.run(None), RunOutcome::Fixpoint { generation: 1 });
+}
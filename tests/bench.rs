@@ -0,0 +1,106 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use aoc_2025_rust::bench::{compare, load_report, save_report, BenchResult, ReportError, Stats};
+
+const HEADER: &str = "day,part,runs,parse_min_us,parse_median_us,parse_max_us,solve_min_us,solve_median_us,solve_max_us,total_min_us,total_median_us,total_max_us";
+
+/// Path of a file in the temp directory unique to this test process.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc_bench_{}_{name}.csv", std::process::id()))
+}
+
+fn stats(min: u64, median: u64, max: u64) -> Stats {
+    Stats { min: Duration::from_micros(min), median: Duration::from_micros(median), max: Duration::from_micros(max) }
+}
+
+/// Result with the given median total time in microseconds.
+fn result(day: u8, part: u8, median: u64) -> BenchResult {
+    BenchResult { day, part, runs: 5, parse: stats(1, 2, 3), solve: stats(4, median - 2, 9 + median), total: stats(5, median, 12 + median) }
+}
+
+/// Write `content` to a temp file and load it as a report.
+fn load_csv(name: &str, content: &str) -> Result<Vec<BenchResult>, ReportError> {
+    let path = temp_path(name);
+    fs::write(&path, content).unwrap();
+    let report = load_report(&path);
+    fs::remove_file(&path).unwrap();
+    report
+}
+
+#[test]
+fn stats_of_samples() {
+    let samples = [7, 1, 4, 9, 3].map(Duration::from_micros);
+    assert_eq!(Stats::from_samples(samples), stats(1, 4, 9));
+    assert_eq!(Stats::from_samples([Duration::from_micros(6)]), stats(6, 6, 6));
+}
+
+#[test]
+fn reports_survive_a_round_trip() {
+    let results = vec![result(1, 1, 10), result(1, 2, 250), result(12, 1, 1_000_000)];
+    let path = temp_path("round_trip");
+    save_report(&path, &results).unwrap();
+    let csv = fs::read_to_string(&path).unwrap();
+    let loaded = load_report(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(csv.lines().next(), Some(HEADER));
+    assert_eq!(csv.lines().nth(1), Some("1,1,5,1,2,3,4,8,19,5,10,22"));
+    assert_eq!(loaded, results);
+}
+
+#[test]
+fn durations_are_stored_in_whole_microseconds() {
+    let mut precise = result(3, 1, 100);
+    precise.total.median += Duration::from_nanos(999);
+    let path = temp_path("microseconds");
+    save_report(&path, &[precise]).unwrap();
+    let loaded = load_report(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, [result(3, 1, 100)]);
+}
+
+#[test]
+fn blank_lines_are_skipped() {
+    let report = load_csv("blank", &format!("{HEADER}\n\n1,1,5,1,2,3,4,8,19,5,10,22\n  \n")).unwrap();
+    assert_eq!(report, [result(1, 1, 10)]);
+}
+
+#[test]
+fn malformed_reports_fail_with_their_line() {
+    let invalid_line = |name: &str, content: &str| match load_csv(name, content) {
+        Err(ReportError::Invalid(_, line)) => Some(line),
+        _ => None,
+    };
+    assert_eq!(invalid_line("empty", ""), Some(1));
+    assert_eq!(invalid_line("header", "day,part\n1,1,5,1,2,3,4,8,19,5,10,22\n"), Some(1));
+    assert_eq!(invalid_line("short", &format!("{HEADER}\n1,1,5,1,2,3,4,8,19,5,10,22\n1,2,5,1\n")), Some(3));
+    assert_eq!(invalid_line("long", &format!("{HEADER}\n1,1,5,1,2,3,4,8,19,5,10,22,0\n")), Some(2));
+    assert_eq!(invalid_line("number", &format!("{HEADER}\n1,1,5,1,2,x,4,8,19,5,10,22\n")), Some(2));
+    assert_eq!(invalid_line("negative", &format!("{HEADER}\n1,1,-5,1,2,3,4,8,19,5,10,22\n")), Some(2));
+    assert_eq!(invalid_line("day", &format!("{HEADER}\n256,1,5,1,2,3,4,8,19,5,10,22\n")), Some(2));
+}
+
+#[test]
+fn missing_report_fails() {
+    assert!(matches!(load_report(&temp_path("missing")), Err(ReportError::Io(..))));
+}
+
+#[test]
+fn comparison_reports_slowdowns_above_threshold() {
+    let previous = [result(1, 1, 100), result(1, 2, 100), result(2, 1, 100), result(3, 1, 100)];
+    let current = [result(1, 1, 110), result(1, 2, 111), result(2, 1, 50), result(4, 1, 1000)];
+    let regressions = compare(&previous, &current, 0.1);
+    assert_eq!(regressions.len(), 1);
+    let regression = &regressions[0];
+    assert_eq!((regression.day, regression.part), (1, 2));
+    assert_eq!((regression.previous, regression.current), (Duration::from_micros(100), Duration::from_micros(111)));
+    assert_eq!(regression.to_string(), "day 1 part 2: median 100µs -> 111µs (+11%)");
+}
+
+#[test]
+fn zero_threshold_reports_any_slowdown() {
+    let previous = [result(1, 1, 100)];
+    assert_eq!(compare(&previous, &[result(1, 1, 101)], 0.0).len(), 1);
+    assert!(compare(&previous, &[result(1, 1, 100)], 0.0).is_empty());
+    assert!(compare(&[], &previous, 0.0).is_empty());
+}
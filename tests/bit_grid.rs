@@ -0,0 +1,81 @@
+use proptest::prelude::*;
+use aoc_2025_rust::coord_grid::{BitGrid, DynGrid, GridLike, GridLikeMut, Vec2};
+
+/// Random boolean grid with up to `max_height` rows and `max_width` columns (widths span several words).
+fn grid(max_height: usize, max_width: usize) -> impl Strategy<Value=DynGrid<bool>> {
+    (1..=max_height, 1..=max_width).prop_flat_map(|(height, width)| {
+        prop::collection::vec(any::<bool>(), height * width).prop_map(move |cells| {
+            let mut grid = DynGrid::create((height, width));
+            grid.raw_mut().copy_from_slice(&cells);
+            grid
+        })
+    })
+}
+
+fn to_dyn_grid(grid: &BitGrid) -> DynGrid<bool> {
+    let mut dyn_grid = DynGrid::create(grid.shape());
+    grid.iter_values_with_coords().for_each(|(coords, v)| dyn_grid.set(coords, v).unwrap());
+    dyn_grid
+}
+
+fn fits(grid: &DynGrid<bool>, other: &DynGrid<bool>, (i0, j0): (usize, usize)) -> bool {
+    i0 + other.shape().0 <= grid.shape().0 && j0 + other.shape().1 <= grid.shape().1
+}
+
+proptest! {
+    #[test]
+    fn shifted_matches_cells(grid in grid(6, 200), di in -7..7isize, dj in -260..260isize) {
+        let mut expected = DynGrid::create(grid.shape());
+        for ((i, j), v) in grid.iter_values_with_coords().filter(|(_, v)| *v) {
+            if let (Some(new_i), Some(new_j)) = (i.checked_add_signed(di), j.checked_add_signed(dj)) {
+                let _ = expected.set((new_i, new_j), v);
+            }
+        }
+        let shifted = BitGrid::from(&grid).shifted(Vec2::new(di, dj));
+        prop_assert_eq!(to_dyn_grid(&shifted), expected.clone());
+        prop_assert_eq!(shifted.count_ones(), expected.iter().filter(|v| **v).count());
+    }
+
+    #[test]
+    fn overlaps_and_union_match_cells(grid in grid(8, 200), other in grid(4, 140), i0 in 0..10usize, j0 in 0..200usize) {
+        let (mut bit_grid, bit_other) = (BitGrid::from(&grid), BitGrid::from(&other));
+        if !fits(&grid, &other, (i0, j0)) {
+            prop_assert!(bit_grid.overlaps(&bit_other, (i0, j0)).is_err());
+            prop_assert!(bit_grid.union_at(&bit_other, (i0, j0)).is_err());
+            prop_assert_eq!(to_dyn_grid(&bit_grid), grid);
+            return Ok(());
+        }
+        let mut expected = grid.clone();
+        let mut overlap = false;
+        for ((i, j), v) in other.iter_values_with_coords().filter(|(_, v)| *v) {
+            overlap |= grid.get((i0 + i, j0 + j)).unwrap();
+            expected.set((i0 + i, j0 + j), v).unwrap();
+        }
+        prop_assert_eq!(bit_grid.overlaps(&bit_other, (i0, j0)).unwrap(), overlap);
+        bit_grid.union_at(&bit_other, (i0, j0)).unwrap();
+        prop_assert_eq!(to_dyn_grid(&bit_grid), expected);
+    }
+}
+
+#[test]
+fn placements_at_the_edges() {
+    let mut grid = BitGrid::create((3, 130));
+    let mut piece = BitGrid::create((2, 2));
+    piece.set_all(true);
+    assert!(grid.union_at(&piece, (1, 128)).is_ok());
+    assert_eq!(grid.row_words(2), [0, 0, 0b11]);
+    assert!(grid.overlaps(&piece, (1, 127)).unwrap());
+    assert!(!grid.overlaps(&piece, (0, 62)).unwrap());
+    assert!(grid.overlaps(&piece, (1, 129)).is_err());
+    assert!(grid.union_at(&piece, (2, 0)).is_err());
+    assert!(grid.union_at(&piece, (0, 129)).is_err());
+}
+
+#[test]
+fn set_all_keeps_unused_bits_clear() {
+    let mut grid = BitGrid::create((2, 70));
+    grid.set_all(true);
+    assert_eq!(grid.count_ones(), 140);
+    assert_eq!(grid.shifted(Vec2::new(0, 1)).count_ones(), 138);
+    assert_eq!(grid.shifted(Vec2::new(1, -64)).count_ones(), 6);
+}
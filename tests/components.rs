@@ -0,0 +1,64 @@
+use aoc_2025_rust::coord_grid::{Component, DynGrid, GridLike, Neighbourhood};
+
+const GARDEN: &str = "\
+AAB.
+A.BB
+..#.
+C..#";
+
+fn garden() -> DynGrid<char> {
+    GARDEN.parse().unwrap()
+}
+
+#[test]
+fn regions_have_size_perimeter_and_bounding_box() {
+    let components = garden().label_regions(|c| c != '.', Neighbourhood::VonNeumann);
+    assert_eq!(components.components.len(), 5);
+    assert_eq!(components.component_at((0, 0)), Some(&Component { label: 1, size: 3, bounding_box: ((0, 0), (1, 1)), perimeter: 8 }));
+    assert_eq!(components.component_at((1, 3)), Some(&Component { label: 2, size: 3, bounding_box: ((0, 2), (1, 3)), perimeter: 8 }));
+    assert_eq!(components.component_at((3, 0)).map(|c| (c.size, c.perimeter)), Some((1, 4)));
+    assert_eq!(components.component_at((1, 1)), None);
+    assert_eq!(components.get(0), None);
+    assert_ne!(components.labels.get((2, 2)).unwrap(), components.labels.get((3, 3)).unwrap());
+}
+
+#[test]
+fn diagonal_pair_is_connected_only_for_moore() {
+    let grid = garden();
+    let von_neumann = grid.label_components(|c| c == '#', Neighbourhood::VonNeumann);
+    assert_eq!(von_neumann.components.iter().map(|c| (c.size, c.perimeter)).collect::<Vec<_>>(), [(1, 4), (1, 4)]);
+    let moore = grid.label_components(|c| c == '#', Neighbourhood::Moore);
+    assert_eq!(moore.components, [Component { label: 1, size: 2, bounding_box: ((2, 2), (3, 3)), perimeter: 8 }]);
+}
+
+#[test]
+fn different_values_satisfying_predicate_are_connected() {
+    // A and B touch and both match, so they form one component
+    let grid = garden();
+    let components = grid.label_components(|c| c.is_ascii_uppercase(), Neighbourhood::VonNeumann);
+    assert_eq!(components.components.iter().map(|c| (c.size, c.perimeter)).collect::<Vec<_>>(), [(6, 14), (1, 4)]);
+    assert_eq!(components.labels.get((0, 1)).unwrap(), components.labels.get((0, 2)).unwrap());
+    let mut filled = grid.flood_fill((0, 0), |c| c.is_ascii_uppercase(), Neighbourhood::VonNeumann);
+    filled.sort();
+    assert_eq!(filled, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 2), (1, 3)]);
+}
+
+#[test]
+fn regions_only_connect_equal_values() {
+    // A and B touch, but form separate regions
+    let regions = garden().label_regions(|c| c.is_ascii_uppercase(), Neighbourhood::Moore);
+    assert_eq!(regions.components.iter().map(|c| (c.size, c.perimeter)).collect::<Vec<_>>(), [(3, 8), (3, 8), (1, 4)]);
+    assert_ne!(regions.labels.get((0, 1)).unwrap(), regions.labels.get((0, 2)).unwrap());
+}
+
+#[test]
+fn flood_fill_follows_predicate() {
+    let grid = garden();
+    let mut empty = grid.flood_fill((1, 1), |c| c == '.', Neighbourhood::VonNeumann);
+    empty.sort();
+    assert_eq!(empty, [(1, 1), (2, 0), (2, 1), (3, 1), (3, 2)]);
+    assert_eq!(grid.flood_fill((2, 2), |c| c == '#', Neighbourhood::VonNeumann), [(2, 2)]);
+    assert_eq!(grid.flood_fill((2, 2), |c| c == '#', Neighbourhood::Moore), [(2, 2), (3, 3)]);
+    assert!(grid.flood_fill((0, 0), |c| c == '.', Neighbourhood::Moore).is_empty());
+    assert!(grid.flood_fill((9, 9), |_| true, Neighbourhood::Moore).is_empty());
+}
@@ -0,0 +1,125 @@
+use proptest::prelude::*;
+use aoc_2025_rust::coord_grid::Turn;
+use aoc_2025_rust::days::dial::Dial;
+use aoc_2025_rust::days::dial_script::{parse, run, ScriptError};
+
+/// Reference implementation: move one click at a time, counting every click ending at 0.
+fn rotate_stepwise(size: u64, position: &mut u64, turn: Turn, clicks: u64) -> u64 {
+    let mut crossings = 0;
+    for _ in 0..clicks {
+        *position = match turn {
+            Turn::Right => (*position + 1) % size,
+            _ => (*position + size - 1) % size,
+        };
+        if *position == 0 {
+            crossings += 1;
+        }
+    }
+    crossings
+}
+
+fn turn() -> impl Strategy<Value=Turn> {
+    prop_oneof![Just(Turn::Left), Just(Turn::Right)]
+}
+
+proptest! {
+    #[test]
+    fn rotations_match_stepwise_simulation(
+        (size, start) in (1..150u64).prop_flat_map(|size| (Just(size), 0..size)),
+        rotations in prop::collection::vec((turn(), 0..1000u64), 0..50),
+    ) {
+        let mut dial = Dial::new(size, start);
+        let mut position = start;
+        let (mut landings, mut crossings) = (0, 0);
+        for (turn, clicks) in rotations {
+            let expected = rotate_stepwise(size, &mut position, turn, clicks);
+            prop_assert_eq!(dial.rotate(turn, clicks), expected);
+            prop_assert_eq!(dial.position(), position);
+            crossings += expected;
+            if position == 0 {
+                landings += 1;
+            }
+        }
+        prop_assert_eq!(dial.zero_crossings(), crossings);
+        prop_assert_eq!(dial.zero_landings(), landings);
+    }
+
+    #[test]
+    fn huge_rotations_do_not_overflow(
+        (size, start) in (1..150u64).prop_flat_map(|size| (Just(size), 0..size)),
+        turn in turn(),
+        clicks in (u64::MAX - 1000)..=u64::MAX,
+    ) {
+        // every full revolution crosses 0 once and leads back to the same position
+        let mut dial = Dial::new(size, start);
+        let mut position = start;
+        let expected = clicks / size + rotate_stepwise(size, &mut position, turn, clicks % size);
+        prop_assert_eq!(dial.rotate(turn, clicks), expected);
+        prop_assert_eq!(dial.position(), position);
+    }
+
+    #[test]
+    fn huge_dials_do_not_overflow(
+        (size, start) in ((u64::MAX - 1000)..=u64::MAX).prop_flat_map(|size| (Just(size), (size - 1000)..size)),
+        turn in turn(),
+    ) {
+        let mut dial = Dial::new(size, start);
+        prop_assert_eq!(dial.rotate(turn, size), 1);
+        prop_assert_eq!(dial.position(), start);
+        // starting within 1000 clicks below `size`, only a right turn reaches 0
+        prop_assert_eq!(dial.rotate(turn, 1000), u64::from(turn == Turn::Right));
+    }
+}
+
+#[test]
+fn full_revolutions_cross_zero_once_each() {
+    let mut dial = Dial::new(100, 0);
+    assert_eq!(dial.rotate(Turn::Left, 1000), 10);
+    assert_eq!(dial.rotate(Turn::Right, 1000), 10);
+    assert_eq!(dial.position(), 0);
+    assert_eq!(dial.zero_landings(), 2);
+}
+
+#[test]
+#[should_panic]
+fn start_outside_dial_panics() {
+    Dial::new(10, 10);
+}
+
+/// Run a script on the puzzle's dial, returning zero landings and crossings.
+fn run_script(script: &str) -> Result<(u64, u64), ScriptError> {
+    let mut dial = Dial::default();
+    run(&parse(script)?, &mut dial)?;
+    Ok((dial.zero_landings(), dial.zero_crossings()))
+}
+
+#[test]
+fn script_accepts_puzzle_input() {
+    let input = std::fs::read_to_string(aoc_2025_rust::input::input_dir().join("day_01_example.txt")).unwrap();
+    assert_eq!(run_script(&input), Ok((3, 6)));
+}
+
+#[test]
+fn script_with_comments_set_and_repeat() {
+    let script = "
+        # start at 0 instead of 50
+        SET 0
+        REPEAT 3 {  // three full turns...
+            R 100
+            REPEAT 2 { L50 }
+        }
+        L1 R1       # ...and back to 0
+    ";
+    assert_eq!(run_script(script), Ok((7, 7)));
+}
+
+#[test]
+fn script_errors_report_line_and_column() {
+    assert_eq!(parse("L10\n  X5"), Err(ScriptError::UnknownInstruction { word: "X".to_string(), line: 2, column: 3 }));
+    assert_eq!(parse("R10 L"), Err(ScriptError::Expected { expected: "a number", found: None, line: 1, column: 6 }));
+    assert_eq!(parse("R 1 { L2 }"), Err(ScriptError::Expected { expected: "an instruction", found: Some("{".to_string()), line: 1, column: 5 }));
+    assert_eq!(parse("R1\nREPEAT 2 {\n  L3"), Err(ScriptError::UnclosedBlock { line: 2, column: 1 }));
+    assert_eq!(parse("L-5"), Err(ScriptError::UnexpectedChar { char: '-', line: 1, column: 2 }));
+    assert_eq!(parse("L99999999999999999999"), Err(ScriptError::InvalidNumber { number: "99999999999999999999".to_string(), line: 1, column: 2 }));
+    assert_eq!(run_script("R1\n SET 100"), Err(ScriptError::PositionOffDial { position: 100, size: 100, line: 2, column: 2 }));
+}
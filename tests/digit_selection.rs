@@ -0,0 +1,49 @@
+use itertools::Itertools;
+use proptest::prelude::*;
+use aoc_2025_rust::days::digit_selection::{largest_subsequence, Decimal};
+
+/// Reference implementation: try every choice of `k` positions.
+fn largest_by_brute_force(digits: &[u8], k: usize) -> Vec<u8> {
+    (0..digits.len()).combinations(k)
+        .map(|indices| indices.iter().map(|&i| digits[i]).collect::<Vec<u8>>())
+        .max()
+        .unwrap_or_default()
+}
+
+proptest! {
+    #[test]
+    fn selection_matches_brute_force(digits in prop::collection::vec(1..10u8, 0..12), k in 0..12usize) {
+        let Some(selection) = largest_subsequence(&digits, k) else {
+            prop_assert!(k > digits.len());
+            return Ok(());
+        };
+        prop_assert_eq!(selection.indices.len(), k);
+        prop_assert!(selection.indices.windows(2).all(|pair| pair[0] < pair[1]));
+        let picked: Vec<u8> = selection.indices.iter().map(|&i| digits[i]).collect();
+        prop_assert_eq!(&picked, &largest_by_brute_force(&digits, k));
+        prop_assert_eq!(selection.value, Decimal::from_digits(&picked));
+    }
+
+    #[test]
+    fn decimal_sum_matches_u64(a in 0..u64::MAX / 2, b in 0..u64::MAX / 2) {
+        prop_assert_eq!(Decimal::from(a) + Decimal::from(b), Decimal::from(a + b));
+    }
+}
+
+#[test]
+fn selection_reports_indices() {
+    let digits = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+    let selection = largest_subsequence(&digits, 2).unwrap();
+    assert_eq!(selection.indices, [6, 11]);
+    assert_eq!(selection.value.to_u64(), Some(92));
+    assert_eq!(largest_subsequence(&digits, 16), None);
+}
+
+#[test]
+fn selection_beyond_u64() {
+    let digits: Vec<u8> = (0..40).map(|i| (i % 9 + 1) as u8).collect();
+    let selection = largest_subsequence(&digits, 25).unwrap();
+    assert_eq!(selection.value.to_u64(), None);
+    assert_eq!(selection.value.to_string(), "9891234567891234567891234");
+    assert_eq!((selection.value.clone() + selection.value).to_string(), "19782469135782469135782468");
+}
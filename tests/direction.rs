@@ -0,0 +1,57 @@
+use strum::IntoEnumIterator;
+use aoc_2025_rust::coord_grid::{Direction, Heading, Point, Turn};
+
+#[test]
+fn arrows_round_trip() {
+    for direction in Direction::iter() {
+        assert_eq!(Direction::from_char(direction.to_arrow()), Some(direction));
+    }
+    let parsed: Vec<Option<Direction>> = "^>v<URDLNESW".chars().map(Direction::from_char).collect();
+    assert!(parsed.chunks(4).all(|chunk| chunk == Direction::main_directions().map(Some)));
+    assert_eq!(Direction::from_char('x'), None);
+}
+
+#[test]
+fn turns_compose() {
+    for direction in Direction::iter() {
+        let four_turns = (0..4).fold(direction, |d, _| d.turn_90_clockwise());
+        assert_eq!(four_turns, direction);
+        assert_eq!(direction.turn_90_clockwise().turn_90_anticlockwise(), direction);
+        assert_eq!(direction.turn_45_clockwise().turn_45_clockwise(), direction.turn_90_clockwise());
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.opposite().offset(), -direction.offset());
+        assert_eq!(direction.turn_clockwise(90), Ok(direction.turn_90_clockwise()));
+        assert_eq!(direction.turn_clockwise(360), Ok(direction));
+        assert_eq!(direction.turn_clockwise(135), direction.turn_anticlockwise(225));
+        assert!(direction.turn_clockwise(30).is_err());
+        assert!(direction.turn_anticlockwise(30).is_err());
+        assert_eq!(direction.turn_anticlockwise(360 * 1_000_000 + 90), Ok(direction.turn_90_anticlockwise()));
+        assert_eq!(direction.turn_anticlockwise(usize::MAX - usize::MAX % 360), Ok(direction));
+        assert_eq!(direction.is_diagonal(), Direction::diagonals().contains(&direction));
+        for turn in [Turn::Left, Turn::Right, Turn::Around] {
+            assert_eq!(direction.turn(turn).turn(turn.reversed()), direction);
+        }
+        assert_eq!(direction.turn(Turn::Left).turn(Turn::Left), direction.turn(Turn::Around));
+    }
+}
+
+#[test]
+fn turns_parse_from_letters() {
+    assert_eq!(Turn::from_char('L'), Some(Turn::Left));
+    assert_eq!(Turn::from_char('R'), Some(Turn::Right));
+    assert_eq!(Turn::from_char('U'), Some(Turn::Around));
+    assert_eq!(Turn::from_char('B'), None);
+    assert_eq!(Turn::from_char('l'), None);
+}
+
+#[test]
+fn headings_walk_and_turn() {
+    let mut heading = Heading::new(Point::ORIGIN, Direction::North);
+    assert_eq!(heading.ahead(), Point::new(-1, 0));
+    assert_eq!(heading.step(), Point::new(-1, 0));
+    heading.turn(Turn::Right);
+    assert_eq!(heading.step_by(3), Point::new(-1, 3));
+    assert_eq!(heading.step_by(-1), Point::new(-1, 2));
+    assert_eq!(heading.turned(Turn::Around).direction, Direction::West);
+    assert_eq!(heading.direction, Direction::East);
+}
@@ -0,0 +1,60 @@
+use aoc_2025_rust::answers::AnswerStore;
+use aoc_2025_rust::days;
+use aoc_2025_rust::input::{input_dir, InputSource};
+
+/// Run a solution on an input file in `input/` and return its answer as text.
+fn solve_file(day: u8, part: u8, file_name: &str) -> String {
+    let solution = days::find(day, part).expect("no solution registered");
+    let input = InputSource::Path(input_dir().join(file_name)).read().expect("unable to read input");
+    solution.solve(&input).expect("solution failed").to_string()
+}
+
+macro_rules! example_test {
+    ($(#[$attr:meta])* $name:ident, $day:expr, $part:expr, $file_name:expr, $expected:expr) => {
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            assert_eq!(solve_file($day, $part, $file_name), $expected);
+        }
+    };
+}
+
+example_test!(day_01_1, 1, 1, "day_01_example.txt", "3");
+example_test!(day_01_2, 1, 2, "day_01_example.txt", "6");
+example_test!(day_02_1, 2, 1, "day_02_example.txt", "1227775554");
+example_test!(day_02_2, 2, 2, "day_02_example.txt", "4174379265");
+example_test!(day_03_1, 3, 1, "day_03_example.txt", "357");
+example_test!(day_03_2, 3, 2, "day_03_example.txt", "3121910778619");
+example_test!(day_04_1, 4, 1, "day_04_example.txt", "13");
+example_test!(day_04_2, 4, 2, "day_04_example.txt", "43");
+example_test!(day_05_1, 5, 1, "day_05_example.txt", "3");
+example_test!(day_05_2, 5, 2, "day_05_example.txt", "14");
+example_test!(day_06_1, 6, 1, "day_06_example.txt", "4277556");
+example_test!(day_06_2, 6, 2, "day_06_example.txt", "3263827");
+example_test!(day_07_1, 7, 1, "day_07_example.txt", "21");
+example_test!(day_07_2, 7, 2, "day_07_example.txt", "40");
+example_test!(day_08_1, 8, 1, "day_08_example.txt", "40");
+example_test!(day_08_2, 8, 2, "day_08_example.txt", "25272");
+example_test!(day_09_1, 9, 1, "day_09_example.txt", "50");
+example_test!(day_09_2, 9, 2, "day_09_example.txt", "24");
+example_test!(day_10_1, 10, 1, "day_10_example.txt", "7");
+example_test!(day_10_2, 10, 2, "day_10_example.txt", "33");
+example_test!(day_11_1, 11, 1, "day_11_example.txt", "5");
+example_test!(day_11_2, 11, 2, "day_11_example_2.txt", "2");
+example_test!(
+    #[ignore = "far too slow unoptimised, run with `cargo test --release -- --ignored`"]
+    day_12_1, 12, 1, "day_12_example.txt", "2"
+);
+
+/// Check the real puzzle inputs against the answers recorded in `input/answers.toml`
+/// (see `aoc run --record`). Solutions without a recorded answer are skipped.
+#[test]
+fn real_inputs() {
+    let store = AnswerStore::load_default().expect("unable to load answer store");
+    for solution in days::all() {
+        let (day, part) = (solution.day(), solution.part());
+        let Some(expected) = store.get(day, part, "input") else { continue };
+        let answer = solve_file(day, part, &format!("day_{day:02}.txt"));
+        assert_eq!(answer, expected, "wrong answer for day {day} part {part}");
+    }
+}
@@ -0,0 +1,125 @@
+use std::io;
+use aoc_2025_rust::coord_grid::{Direction, DynGrid, Grid, GridCell, GridError, GridLike, GridLikeMut};
+
+#[test]
+fn accesses_outside_a_grid_fail() {
+    let mut grid: Grid<3, 2, u8> = Grid::create();
+    assert!(matches!(grid.get((2, 0)), Err(GridError::OutOfBounds { coords: (2, 0), shape: (2, 3) })));
+    assert!(matches!(grid.set((0, 3), 1), Err(GridError::OutOfBounds { coords: (0, 3), shape: (2, 3) })));
+    assert!(matches!(grid.get((usize::MAX, 0)), Err(GridError::OutOfBounds { coords: (isize::MAX, 0), .. })));
+    assert_eq!(grid.iter().copied().max(), Some(0));
+}
+
+#[test]
+fn accesses_outside_a_dyn_grid_fail() {
+    let mut grid: DynGrid<u8> = DynGrid::create((2, 3));
+    assert!(matches!(grid.get((1, 3)), Err(GridError::OutOfBounds { coords: (1, 3), shape: (2, 3) })));
+    assert!(matches!(grid.set((2, 2), 1), Err(GridError::OutOfBounds { coords: (2, 2), shape: (2, 3) })));
+    assert_eq!(grid.raw(), [0; 6]);
+    let empty: DynGrid<u8> = DynGrid::create((0, 0));
+    assert!(matches!(empty.get((0, 0)), Err(GridError::OutOfBounds { coords: (0, 0), shape: (0, 0) })));
+}
+
+#[test]
+fn moves_off_the_grid_fail() {
+    let grid: DynGrid<u8> = DynGrid::create((2, 3));
+    assert_eq!(grid.move_coords((0, 0), Direction::South).unwrap(), (1, 0));
+    assert!(matches!(grid.move_coords((0, 0), Direction::North), Err(GridError::OutOfBounds { coords: (-1, 0), shape: (2, 3) })));
+    assert!(matches!(grid.move_coords((0, 0), Direction::Northwest), Err(GridError::OutOfBounds { coords: (-1, -1), .. })));
+    assert!(matches!(grid.move_coords((1, 2), Direction::East), Err(GridError::OutOfBounds { coords: (1, 3), .. })));
+}
+
+#[test]
+fn errors_describe_the_problem() {
+    let grid: DynGrid<u8> = DynGrid::create((2, 3));
+    assert_eq!(grid.get((5, 1)).unwrap_err().to_string(), "coordinates (5, 1) out of bounds for grid of shape (2, 3)");
+}
+
+/// Cell type accepting only `.`, `#` and `O`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+enum Cell {
+    #[default]
+    Empty,
+    Wall,
+    Boulder,
+}
+
+impl GridCell for Cell {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Cell::Empty),
+            '#' => Some(Cell::Wall),
+            'O' => Some(Cell::Boulder),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::Boulder => 'O',
+        }
+    }
+}
+
+#[test]
+fn cells_are_parsed_and_printed() {
+    let grid: Grid<3, 2, Cell> = "#.O\n..#".parse().unwrap();
+    assert_eq!(grid.get((0, 2)).unwrap(), Cell::Boulder);
+    assert_eq!(grid.to_string(), "#.O\n..#");
+    let dyn_grid = DynGrid::<Cell>::try_from("#.O\n..#").unwrap();
+    assert_eq!(DynGrid::from(grid), dyn_grid);
+    assert_eq!("#.".parse::<DynGrid<bool>>().unwrap().raw(), [true, false]);
+}
+
+#[test]
+fn unknown_cell_characters_are_rejected() {
+    assert!(matches!("#.O\n.x#".parse::<DynGrid<Cell>>(), Err(GridError::InvalidChar { char: 'x', line: 2, column: 2 })));
+    assert!(matches!(Grid::<3, 1, Cell>::try_from("O. "), Err(GridError::InvalidChar { char: ' ', line: 1, column: 3 })));
+    assert!(matches!("#O".parse::<DynGrid<bool>>(), Err(GridError::InvalidChar { char: 'O', line: 1, column: 2 })));
+    assert_eq!(bool::from_char('O'), None);
+    assert_eq!(char::from_char('O'), Some('O'));
+}
+
+#[test]
+fn ragged_rows_are_rejected() {
+    assert!(matches!("#.O\n.#\n...".parse::<DynGrid<Cell>>(), Err(GridError::WrongLineLength { line: 2, found: 2, expected: 3 })));
+    assert!(matches!("##\n###".parse::<DynGrid<char>>(), Err(GridError::WrongLineLength { line: 2, found: 3, expected: 2 })));
+}
+
+#[test]
+fn rows_of_the_wrong_length_are_rejected() {
+    assert!(matches!("#.\n..".parse::<Grid<3, 2, Cell>>(), Err(GridError::WrongLineLength { line: 1, found: 2, expected: 3 })));
+    assert!(matches!("#..\n....".parse::<Grid<3, 2, Cell>>(), Err(GridError::WrongLineLength { line: 2, found: 4, expected: 3 })));
+}
+
+#[test]
+fn wrong_number_of_rows_is_rejected() {
+    assert!(matches!("#..".parse::<Grid<3, 2, Cell>>(), Err(GridError::WrongLineCount { found: 1, expected: 2 })));
+    assert!(matches!("#..\n...\n...".parse::<Grid<3, 2, Cell>>(), Err(GridError::WrongLineCount { found: 3, expected: 2 })));
+    assert!(matches!("".parse::<Grid<3, 2, Cell>>(), Err(GridError::WrongLineCount { found: 0, expected: 2 })));
+}
+
+#[test]
+fn converting_a_dyn_grid_of_another_shape_fails() {
+    let dyn_grid: DynGrid<Cell> = "#.O\n..#".parse().unwrap();
+    assert!(matches!(Grid::<2, 3, Cell>::try_from(dyn_grid.clone()), Err(GridError::WrongShape { found: (2, 3), expected: (3, 2) })));
+    assert!(Grid::<3, 2, Cell>::try_from(dyn_grid).is_ok());
+}
+
+#[test]
+fn read_errors_are_passed_on() {
+    let lines = vec![Ok("#.".to_string()), Err(io::Error::other("disk on fire"))];
+    let error = DynGrid::<Cell>::from_lines(lines.into_iter()).unwrap_err();
+    assert_eq!(error.to_string(), "unable to read grid: disk on fire");
+    assert!(matches!(error, GridError::Io(e) if e.kind() == io::ErrorKind::Other));
+}
+
+#[test]
+fn parse_errors_describe_the_problem() {
+    let error = "#.O\n.x#".parse::<DynGrid<Cell>>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid character 'x' in line 2, column 2");
+    let error = "#.".parse::<Grid<3, 1, Cell>>().unwrap_err();
+    assert_eq!(error.to_string(), "incorrect length of line 1: found 2, expected 3 characters");
+}
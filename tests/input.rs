@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use aoc_2025_rust::input::{input_dir, InputError, InputSource};
+
+/// Path of a file in the temp directory unique to this test process.
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc_input_{}_{name}.txt", std::process::id()))
+}
+
+#[test]
+fn input_dir_is_in_the_crate_root() {
+    assert_eq!(input_dir(), Path::new(env!("CARGO_MANIFEST_DIR")).join("input"));
+}
+
+#[test]
+fn sources_resolve_to_paths() {
+    assert_eq!(InputSource::Day(7).path(), Some(input_dir().join("day_07.txt")));
+    assert_eq!(InputSource::Day(12).path(), Some(input_dir().join("day_12.txt")));
+    assert_eq!(InputSource::Example(3).path(), Some(input_dir().join("day_03_example.txt")));
+    assert_eq!(InputSource::Path(PathBuf::from("a/b.txt")).path(), Some(PathBuf::from("a/b.txt")));
+    assert_eq!(InputSource::Stdin.path(), None);
+    assert_eq!(InputSource::Stdin.to_string(), "stdin");
+    assert_eq!(InputSource::Path(PathBuf::from("a/b.txt")).to_string(), "a/b.txt");
+}
+
+#[test]
+fn only_real_and_example_inputs_have_labels() {
+    assert_eq!(InputSource::Day(1).label().as_deref(), Some("input"));
+    assert_eq!(InputSource::Example(1).label().as_deref(), Some("example"));
+    assert_eq!(InputSource::Path(PathBuf::from("day_01.txt")).label(), None);
+    assert_eq!(InputSource::Stdin.label(), None);
+}
+
+#[test]
+fn paths_to_the_example_are_canonical() {
+    let example = InputSource::Example(1);
+    let relative = input_dir().join("..").join("input").join("day_01_example.txt");
+    assert_eq!(InputSource::Path(relative).canonical(1), example);
+    assert_eq!(InputSource::Path(example.path().unwrap()).canonical(2), InputSource::Path(example.path().unwrap()));
+    assert_eq!(InputSource::Path(temp_path("missing")).canonical(1), InputSource::Path(temp_path("missing")));
+    assert_eq!(InputSource::Stdin.canonical(1), InputSource::Stdin);
+    assert_eq!(example.canonical(1), example);
+}
+
+#[test]
+fn files_are_read() {
+    let path = temp_path("read");
+    fs::write(&path, "1,2\n3,4\n").unwrap();
+    let input = InputSource::Path(path.clone()).read();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(input.unwrap(), "1,2\n3,4\n");
+    assert!(InputSource::Example(1).read().unwrap().lines().count() > 1);
+}
+
+#[test]
+fn missing_files_are_not_found() {
+    let source = InputSource::Path(temp_path("missing"));
+    let error = source.read().unwrap_err();
+    assert!(matches!(&error, InputError::NotFound(s) if *s == source));
+    assert_eq!(error.to_string(), format!("input file {} not found", temp_path("missing").display()));
+    assert!(matches!(InputSource::Example(99).read(), Err(InputError::NotFound(InputSource::Example(99)))));
+    assert!(InputSource::Day(99).read().unwrap_err().to_string().ends_with("not found - save your puzzle input there or pass --input <path>"));
+}
+
+#[test]
+fn directories_cannot_be_read() {
+    let source = InputSource::Path(input_dir());
+    assert!(matches!(source.read(), Err(InputError::Io(s, _)) if s == source));
+}
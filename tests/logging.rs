@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+use log::LevelFilter;
+use aoc_2025_rust::logging::{DayFilter, LogConfig, LogError};
+
+fn args(s: &str) -> Vec<String> {
+    s.split_whitespace().map(str::to_string).collect()
+}
+
+fn config(s: &str) -> LogConfig {
+    let (config, _) = LogConfig::from_args(args(s)).unwrap();
+    config
+}
+
+#[test]
+fn day_filters_are_parsed() {
+    assert_eq!("2=trace".parse::<DayFilter>().unwrap(), DayFilter { day: 2, part: None, level: LevelFilter::Trace });
+    assert_eq!("11.2=debug".parse::<DayFilter>().unwrap(), DayFilter { day: 11, part: Some(2), level: LevelFilter::Debug });
+    assert_eq!("3=OFF".parse::<DayFilter>().unwrap(), DayFilter { day: 3, part: None, level: LevelFilter::Off });
+}
+
+#[test]
+fn invalid_day_filters_are_rejected() {
+    for filter in ["", "2", "=debug", "x=debug", "2.=debug", "2.x=debug", "2=loud", "256=info", "2.1.1=info"] {
+        assert!(matches!(filter.parse::<DayFilter>(), Err(LogError::InvalidFilter(f)) if f == filter), "{filter:?}");
+    }
+}
+
+#[test]
+fn day_filters_match_day_and_part() {
+    let day = DayFilter { day: 2, part: None, level: LevelFilter::Trace };
+    let part = DayFilter { day: 2, part: Some(1), level: LevelFilter::Trace };
+    assert!(day.matches(2, 1) && day.matches(2, 2) && !day.matches(3, 1));
+    assert!(part.matches(2, 1) && !part.matches(2, 2) && !part.matches(1, 1));
+}
+
+#[test]
+fn levels_are_chosen_by_module() {
+    let config = config("-v --log 2=trace,11.2=debug,2.2=off");
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_02_1"), LevelFilter::Trace);
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_02_2"), LevelFilter::Off);
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_11_2"), LevelFilter::Debug);
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_11_1"), LevelFilter::Info);
+    assert_eq!(config.level_for("aoc_2025_rust::days::repeated_ids"), LevelFilter::Info);
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_xx_1"), LevelFilter::Info);
+    assert_eq!(config.level_for("aoc"), LevelFilter::Info);
+    assert_eq!(config.max_level(), LevelFilter::Trace);
+}
+
+#[test]
+fn later_filters_take_precedence() {
+    let config = config("--log 5.1=trace --log 5=error");
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_05_1"), LevelFilter::Error);
+    assert_eq!(config.day_filters.len(), 2);
+}
+
+#[test]
+fn verbosity_sets_the_global_level() {
+    assert_eq!(config(""), LogConfig::default());
+    assert_eq!(config("").level, LevelFilter::Warn);
+    assert_eq!(config("-v").level, LevelFilter::Info);
+    assert_eq!(config("-vv").level, LevelFilter::Debug);
+    assert_eq!(config("-v -v").level, LevelFilter::Debug);
+    assert_eq!(config("-vvv").level, LevelFilter::Trace);
+    assert_eq!(config("-vvvvv").level, LevelFilter::Trace);
+    assert_eq!(config("-q").level, LevelFilter::Off);
+    assert_eq!(config("--quiet").level, LevelFilter::Off);
+}
+
+#[test]
+fn quiet_keeps_day_filters() {
+    let config = config("-q --log 7=debug");
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_07_2"), LevelFilter::Debug);
+    assert_eq!(config.level_for("aoc_2025_rust::days::day_08_2"), LevelFilter::Off);
+    assert_eq!(config.max_level(), LevelFilter::Debug);
+}
+
+#[test]
+fn other_arguments_are_kept_in_order() {
+    let (config, rest) = LogConfig::from_args(args("run -v 7 --log-file log.txt 2 -e -")).unwrap();
+    assert_eq!(rest, ["run", "7", "2", "-e", "-"]);
+    assert_eq!(config.file, Some(PathBuf::from("log.txt")));
+    assert_eq!(config.level, LevelFilter::Info);
+}
+
+#[test]
+fn invalid_logging_options_are_rejected() {
+    assert!(matches!(LogConfig::from_args(args("-q -v")), Err(LogError::QuietAndVerbose)));
+    assert!(matches!(LogConfig::from_args(args("run --log")), Err(LogError::MissingValue(option)) if option == "--log"));
+    assert!(matches!(LogConfig::from_args(args("--log-file")), Err(LogError::MissingValue(option)) if option == "--log-file"));
+    assert!(matches!(LogConfig::from_args(args("--log 2=trace,3")), Err(LogError::InvalidFilter(filter)) if filter == "3"));
+}
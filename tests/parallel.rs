@@ -0,0 +1,56 @@
+#![cfg(feature = "parallel")]
+
+use proptest::prelude::*;
+use aoc_2025_rust::coord_grid::{Automaton, DynGrid, GridLike, GridLikeMut, Neighbourhood, ParallelGridLike};
+use aoc_2025_rust::parallel::map_records;
+
+fn grid() -> impl Strategy<Value=DynGrid<bool>> {
+    (1..20usize, 1..20usize).prop_flat_map(|(height, width)| {
+        prop::collection::vec(any::<bool>(), height * width).prop_map(move |cells| {
+            let mut grid = DynGrid::create((height, width));
+            grid.raw_mut().copy_from_slice(&cells);
+            grid
+        })
+    })
+}
+
+/// Conway's game of life.
+fn life(grid: &DynGrid<bool>, coords: (usize, usize)) -> bool {
+    let alive = grid.get(coords).unwrap();
+    matches!((alive, grid.count_neighbours(coords, Neighbourhood::Moore, |v| v)), (true, 2) | (_, 3))
+}
+
+proptest! {
+    #[test]
+    fn par_map_matches_sequential(grid in grid()) {
+        let f = |(i, j): (usize, usize), v: bool| if v { i * 31 + j } else { 0 };
+        let mut expected = DynGrid::create(grid.shape());
+        grid.iter_values_with_coords().for_each(|(coords, v)| expected.set(coords, f(coords, v)).unwrap());
+        prop_assert_eq!(grid.par_map(f), expected);
+    }
+
+    #[test]
+    fn par_step_matches_step(grid in grid()) {
+        let mut sequential = Automaton::new(grid.clone(), life);
+        let mut parallel = Automaton::new(grid, life);
+        for _ in 0..5 {
+            prop_assert_eq!(parallel.par_step(), sequential.step());
+            prop_assert_eq!(parallel.grid(), sequential.grid());
+        }
+    }
+
+    #[test]
+    fn par_run_matches_run(grid in grid()) {
+        let mut sequential = Automaton::new(grid.clone(), life);
+        let mut parallel = Automaton::new(grid, life);
+        prop_assert_eq!(parallel.par_run(Some(50)), sequential.run(Some(50)));
+        prop_assert_eq!(parallel.grid(), sequential.grid());
+        prop_assert_eq!(parallel.change_counts(), sequential.change_counts());
+    }
+
+    #[test]
+    fn map_records_keeps_order(records in prop::collection::vec(any::<u32>(), 0..500)) {
+        let f = |n: &u32| n.wrapping_mul(2_654_435_761) >> 3;
+        prop_assert_eq!(map_records(&records, f), records.iter().map(f).collect::<Vec<_>>());
+    }
+}
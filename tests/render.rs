@@ -0,0 +1,101 @@
+use std::io;
+use std::io::IsTerminal;
+use aoc_2025_rust::coord_grid::{Border, Colour, ColourMode, DynGrid, GridRenderer};
+
+fn grid() -> DynGrid<char> {
+    "#..\n.#.".parse().unwrap()
+}
+
+#[test]
+fn borders_frame_the_grid() {
+    let grid = grid();
+    let render = |border| GridRenderer::from_cells(&grid).border(border).colour_mode(ColourMode::Never).to_string();
+    assert_eq!(render(Border::None), "#..\n.#.");
+    assert_eq!(render(Border::Ascii), "-----\n|#..|\n|.#.|\n-----");
+    assert_eq!(render(Border::Box), "┌───┐\n│#..│\n│.#.│\n└───┘");
+    assert_eq!(GridRenderer::from_cells(&grid).colour_mode(ColourMode::Never).to_string(), render(Border::Ascii));
+}
+
+#[test]
+fn empty_grids_render_only_the_border() {
+    let empty: DynGrid<char> = DynGrid::create((0, 2));
+    assert_eq!(GridRenderer::from_cells(&empty).border(Border::None).to_string(), "");
+    assert_eq!(GridRenderer::from_cells(&empty).border(Border::Box).to_string(), "┌──┐\n└──┘");
+}
+
+#[test]
+fn cells_are_mapped_with_the_given_function() {
+    let grid = grid();
+    let renderer = GridRenderer::new(&grid, |c| if c == '#' { '█' } else { ' ' }).border(Border::None);
+    assert_eq!(renderer.to_string(), "█  \n █ ");
+}
+
+#[test]
+fn overlays_replace_cells() {
+    let grid = grid();
+    let path: DynGrid<bool> = "..#\n..#".parse().unwrap();
+    let renderer = GridRenderer::from_cells(&grid)
+        .border(Border::None)
+        .colour_mode(ColourMode::Never)
+        .overlay_grid(&path, |on_path| on_path.then_some('*'), Some(Colour::Green))
+        .overlay_coords([(0, 0), (1, 2), (5, 5)], 'S', None);
+    assert_eq!(renderer.to_string(), "S.*\n.#S");
+}
+
+#[test]
+fn smaller_overlay_grids_show_the_cells_below() {
+    let grid = grid();
+    let corner: DynGrid<char> = "xy".parse().unwrap();
+    let renderer = GridRenderer::from_cells(&grid).border(Border::None).overlay_grid(&corner, Some, None);
+    assert_eq!(renderer.to_string(), "xy.\n.#.");
+}
+
+#[test]
+fn never_writes_no_escape_codes() {
+    let grid = grid();
+    let renderer = GridRenderer::from_cells(&grid)
+        .border(Border::None)
+        .colour_mode(ColourMode::Never)
+        .overlay_coords([(0, 1)], 'o', Some(Colour::Red))
+        .highlight([(1, 1)], Colour::Blue);
+    assert_eq!(renderer.to_string(), "#o.\n.#.");
+}
+
+#[test]
+fn always_colours_overlays_and_highlights() {
+    let grid = grid();
+    let renderer = GridRenderer::from_cells(&grid)
+        .border(Border::Ascii)
+        .colour_mode(ColourMode::Always)
+        .overlay_coords([(0, 1)], 'o', Some(Colour::Red))
+        .overlay_coords([(0, 2)], 'x', None)
+        .highlight([(1, 1)], Colour::Blue);
+    assert_eq!(renderer.to_string(), "-----\n|#\x1b[31mo\x1b[0mx|\n|.\x1b[34m#\x1b[0m.|\n-----");
+}
+
+#[test]
+fn later_layers_take_precedence() {
+    let grid = grid();
+    let renderer = GridRenderer::from_cells(&grid)
+        .border(Border::None)
+        .colour_mode(ColourMode::Always)
+        // highlighted, then replaced without colour: the replacement drops the highlight
+        .highlight([(0, 0), (0, 1)], Colour::Yellow)
+        .overlay_coords([(0, 0)], 'a', None)
+        // replaced, then highlighted: the character stays, the colour changes
+        .overlay_coords([(1, 0)], 'b', Some(Colour::Cyan))
+        .highlight([(1, 0)], Colour::Magenta);
+    assert_eq!(renderer.to_string(), "a\x1b[33m.\x1b[0m.\n\x1b[35mb\x1b[0m#.");
+}
+
+#[test]
+fn terminal_modes_follow_their_stream() {
+    let grid = grid();
+    let render = |mode| GridRenderer::from_cells(&grid).border(Border::None).colour_mode(mode).highlight([(0, 0)], Colour::Red).to_string();
+    let (always, never) = (render(ColourMode::Always), render(ColourMode::Never));
+    assert_ne!(always, never);
+    let expected = |terminal: bool| if terminal { always.clone() } else { never.clone() };
+    assert_eq!(render(ColourMode::IfStdoutTerminal), expected(io::stdout().is_terminal()));
+    assert_eq!(render(ColourMode::IfStderrTerminal), expected(io::stderr().is_terminal()));
+    assert_eq!(ColourMode::default(), ColourMode::IfStdoutTerminal);
+}
@@ -0,0 +1,79 @@
+use proptest::prelude::*;
+use aoc_2025_rust::days::repeated_ids::{digits, parse_base, repeated_at_least_stats, repetition_factor, repetition_stats, repetitions, IdRule, IdRuleError, IdStats};
+
+/// Reference implementation: whether the digits of `n` in `base` are exactly `k` copies of a block (never for 0).
+fn is_repetition(n: u64, k: usize, base: u32) -> bool {
+    let digits = digits(n, base);
+    n > 0 && digits.len().is_multiple_of(k) && digits == digits[..digits.len() / k].repeat(k)
+}
+
+fn brute_force(a: u64, b: u64, is_invalid: impl Fn(u64) -> bool) -> IdStats {
+    (a..=b).filter(|&n| is_invalid(n)).map(|n| IdStats { count: 1, sum: n as u128 }).sum()
+}
+
+fn range() -> impl Strategy<Value=(u64, u64)> {
+    prop_oneof![Just(0), (0..12u32).prop_flat_map(|digits| 10u64.pow(digits)..10u64.pow(digits + 1))]
+        .prop_flat_map(|a| (Just(a), a..a + 20_000))
+}
+
+proptest! {
+    #[test]
+    fn repetitions_match_brute_force((a, b) in range(), k in 1..6u32, base in 2..17u32) {
+        let expected: Vec<u64> = (a..=b).filter(|&n| is_repetition(n, k as usize, base)).collect();
+        prop_assert_eq!(repetitions(a..=b, k, base).collect::<Vec<_>>(), expected);
+        prop_assert_eq!(IdRule::Exactly(k).stats(a..=b, base), brute_force(a, b, |n| IdRule::Exactly(k).is_invalid(n, base)));
+    }
+
+    #[test]
+    fn at_least_matches_brute_force((a, b) in range(), n in 1..5u32, base in 2..17u32) {
+        let expected = brute_force(a, b, |id| (n as usize..=digits(id, base).len()).any(|k| is_repetition(id, k, base)));
+        prop_assert_eq!(IdRule::AtLeast(n).stats(a..=b, base), expected);
+    }
+}
+
+#[test]
+fn factors_repeat_blocks() {
+    assert_eq!(repetition_factor(3, 3, 10), 1001001);
+    assert_eq!(repetition_factor(1, 4, 10), 1111);
+    assert_eq!(repetition_factor(2, 2, 2), 0b101);
+    assert_eq!(repetitions(0..=1_000_000, 3, 10).take(3).collect::<Vec<_>>(), [111, 222, 333]);
+}
+
+#[test]
+fn zero_is_not_a_repetition() {
+    assert_eq!(repetitions(0..=12, 1, 10).collect::<Vec<_>>(), (1..=12).collect::<Vec<_>>());
+    assert_eq!(repetition_stats(0..=0, 1, 10), IdStats::default());
+    assert_eq!(repetition_stats(0..=99, 2, 10), IdStats { count: 9, sum: 11 * 45 });
+    assert_eq!(repeated_at_least_stats(0..=99, 2, 10), IdStats { count: 9, sum: 11 * 45 });
+    for rule in [IdRule::Exactly(1), IdRule::AtLeast(1)] {
+        assert!(!rule.is_invalid(0, 10));
+        assert_eq!(rule.stats(0..=5, 10), IdStats { count: 5, sum: 15 });
+    }
+}
+
+#[test]
+fn at_least_counts_each_number_once() {
+    // 111111 is "1" six times, "11" three times and "111" twice
+    assert_eq!(repeated_at_least_stats(111_111..=111_111, 2, 10), IdStats { count: 1, sum: 111_111 });
+    assert_eq!(repeated_at_least_stats(1..=99, 2, 10).count, 9);
+    assert_eq!(repeated_at_least_stats(1..=999_999, 3, 10).count, 9 + 9 + 9 + 90);
+}
+
+#[test]
+fn palindromes_and_custom_rules() {
+    assert_eq!(IdRule::Palindrome.stats(10..=130, 10), IdStats { count: 12, sum: 11 * 45 + 101 + 111 + 121 });
+    assert!(IdRule::Palindrome.is_invalid(0b1001, 2));
+    let all_even = IdRule::Custom(|digits| digits.iter().all(|d| d % 2 == 0));
+    assert_eq!(all_even.stats(1..=30, 10).count, 4 + 5);
+}
+
+#[test]
+fn rules_parse_from_text() {
+    assert!(matches!("exactly:2".parse(), Ok(IdRule::Exactly(2))));
+    assert!(matches!("at-least:3".parse(), Ok(IdRule::AtLeast(3))));
+    assert!(matches!("palindrome".parse(), Ok(IdRule::Palindrome)));
+    assert!(matches!("exactly:0".parse::<IdRule>(), Err(IdRuleError::InvalidRule(rule)) if rule == "exactly:0"));
+    assert!(matches!("twice".parse::<IdRule>(), Err(IdRuleError::InvalidRule(_))));
+    assert_eq!(parse_base("1"), Err(IdRuleError::InvalidBase("1".to_string())));
+    assert_eq!(parse_base("16"), Ok(16));
+}
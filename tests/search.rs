@@ -0,0 +1,103 @@
+use aoc_2025_rust::coord_grid::{DynGrid, GridError, GridLike, Neighbourhood};
+
+const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#G
+####.###
+..#.....";
+
+fn maze() -> DynGrid<char> {
+    MAZE.parse().unwrap()
+}
+
+fn open(_: (usize, usize), c: char) -> bool {
+    c != '#'
+}
+
+fn unit_cost(_: (usize, usize), c: char) -> Option<u64> {
+    (c != '#').then_some(1)
+}
+
+#[test]
+fn bfs_finds_shortest_path() {
+    let maze = maze();
+    let (start, goal) = (maze.position('S').unwrap(), maze.position('G').unwrap());
+    let result = maze.bfs(start, open, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(result.distance(goal), Some(15));
+    assert_eq!(result.distance((6, 7)), Some(13));
+    let path = result.path_to(goal).unwrap();
+    assert_eq!((path.first(), path.last(), path.len()), (Some(&start), Some(&goal), 16));
+    assert!(path.windows(2).all(|step| step[0].0.abs_diff(step[1].0) + step[0].1.abs_diff(step[1].1) == 1));
+    assert!(path.iter().all(|&coords| maze.get(coords).unwrap() != '#'));
+}
+
+#[test]
+fn unreachable_cells_have_no_path() {
+    let result = maze().bfs((0, 0), open, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(result.distance((6, 0)), None);
+    assert_eq!(result.path_to((6, 0)), None);
+    assert_eq!(result.path_to((0, 3)), None);
+    assert_eq!(result.path_to((0, 0)), Some(vec![(0, 0)]));
+}
+
+#[test]
+fn dijkstra_matches_bfs_for_unit_costs() {
+    let maze = maze();
+    let bfs = maze.bfs((0, 0), open, Neighbourhood::VonNeumann).unwrap();
+    let dijkstra = maze.dijkstra((0, 0), unit_cost, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(dijkstra.distances(), bfs.distances());
+}
+
+#[test]
+fn dijkstra_uses_entry_costs() {
+    let costs: DynGrid<char> = "131\n191\n111".parse().unwrap();
+    let cost = |_, c: char| c.to_digit(10).map(u64::from);
+    let result = costs.dijkstra((0, 0), cost, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(result.distance((2, 2)), Some(4));
+    assert_eq!(result.path_to((2, 2)).unwrap(), [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn astar_with_admissible_heuristic_matches_dijkstra() {
+    let maze = maze();
+    let dijkstra = maze.dijkstra((0, 0), unit_cost, Neighbourhood::VonNeumann).unwrap();
+    for (goal, _) in maze.iter_values_with_coords().filter(|(_, c)| *c != '#') {
+        let manhattan = |(i, j): (usize, usize)| (i.abs_diff(goal.0) + j.abs_diff(goal.1)) as u64;
+        let astar = maze.astar((0, 0), goal, unit_cost, manhattan, Neighbourhood::VonNeumann).unwrap();
+        assert_eq!(astar.distance(goal), dijkstra.distance(goal), "distance to {goal:?}");
+    }
+}
+
+#[test]
+fn astar_with_inconsistent_heuristic_is_optimal() {
+    // The heuristic never overestimates, but drops by 7 between (1, 0) and (1, 1) although the
+    // step costs 1, so (1, 1) is first reached through the expensive cell (0, 1) and must be expanded again.
+    let costs: DynGrid<char> = "159\n111".parse().unwrap();
+    let estimates: DynGrid<char> = "640\n921".parse().unwrap();
+    let cost = |_, c: char| c.to_digit(10).map(u64::from);
+    let heuristic = |coords| estimates.get(coords).unwrap().to_digit(10).unwrap() as u64;
+    let result = costs.astar((0, 0), (0, 2), cost, heuristic, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(result.distance((0, 2)), Some(12));
+    assert_eq!(result.path_to((0, 2)).unwrap(), [(0, 0), (1, 0), (1, 1), (1, 2), (0, 2)]);
+}
+
+#[test]
+fn astar_stops_at_goal() {
+    let corridor: DynGrid<char> = "..........".parse().unwrap();
+    let heuristic = |(_, j): (usize, usize)| j.abs_diff(6) as u64;
+    let result = corridor.astar((0, 5), (0, 6), unit_cost, heuristic, Neighbourhood::VonNeumann).unwrap();
+    assert_eq!(result.distance((0, 6)), Some(1));
+    assert_eq!(result.distance((0, 7)), None);
+    assert_eq!(result.distance((0, 9)), None);
+}
+
+#[test]
+fn searches_outside_the_grid_fail() {
+    let maze = maze();
+    assert!(matches!(maze.bfs((7, 0), open, Neighbourhood::VonNeumann), Err(GridError::OutOfBounds { coords: (7, 0), .. })));
+    assert!(matches!(maze.dijkstra((0, 8), unit_cost, Neighbourhood::VonNeumann), Err(GridError::OutOfBounds { .. })));
+    assert!(matches!(maze.astar((0, 0), (9, 9), unit_cost, |_| 0, Neighbourhood::VonNeumann), Err(GridError::OutOfBounds { coords: (9, 9), .. })));
+}
@@ -0,0 +1,74 @@
+use proptest::prelude::*;
+use aoc_2025_rust::coord_grid::{Direction, Neighbourhood, Point, SparseGrid, Vec2};
+
+#[test]
+fn point_arithmetic() {
+    let (a, b) = (Point::new(2, -3), Vec2::new(-1, 5));
+    assert_eq!(a + b, Point::new(1, 2));
+    assert_eq!(a - b, Point::new(3, -8));
+    assert_eq!(-a, Point::new(-2, 3));
+    assert_eq!(b * 3, Vec2::new(-3, 15));
+    let mut c = a;
+    c += b;
+    c -= b;
+    assert_eq!(c, a);
+    assert_eq!(a.manhattan_distance(&Point::ORIGIN), 5);
+    assert_eq!(a.chebyshev_distance(&b), 8);
+    assert_eq!(a.componentwise_min(&b), Point::new(-1, -3));
+    assert_eq!(a.componentwise_max(&b), Point::new(2, 5));
+    assert_eq!(a.step(Direction::Northeast), Point::new(1, -2));
+    assert_eq!(a.to_string(), "(2, -3)");
+}
+
+#[test]
+fn point_conversions() {
+    assert_eq!(Point::from((3usize, 4usize)), Point::new(3, 4));
+    assert_eq!(<(usize, usize)>::try_from(Point::new(3, 4)), Ok((3, 4)));
+    assert!(<(usize, usize)>::try_from(Point::new(-1, 4)).is_err());
+    assert_eq!(<(isize, isize)>::from(Point::from((-1isize, 4isize))), (-1, 4));
+}
+
+#[test]
+fn removing_edge_points_shrinks_bounding_box() {
+    let mut grid: SparseGrid<char> = [(Point::new(0, 0), 'a'), (Point::new(-2, 5), 'b'), (Point::new(3, 1), 'c')].into_iter().collect();
+    assert_eq!(grid.bounding_box(), Some((Point::new(-2, 0), Point::new(3, 5))));
+    assert_eq!(grid.remove(Point::new(-2, 5)), Some('b'));
+    assert_eq!(grid.bounding_box(), Some((Point::new(0, 0), Point::new(3, 1))));
+    assert_eq!(grid.remove(Point::new(7, 7)), None);
+    assert_eq!(grid.remove(Point::new(3, 1)), Some('c'));
+    assert_eq!(grid.bounding_box(), Some((Point::new(0, 0), Point::new(0, 0))));
+    assert_eq!(grid.remove(Point::new(0, 0)), Some('a'));
+    assert_eq!(grid.bounding_box(), None);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn sparse_grid_neighbours_and_dense_copy() {
+    let grid: SparseGrid<u8> = [(Point::new(-1, -1), 1), (Point::new(0, 0), 2), (Point::new(0, 1), 3)].into_iter().collect();
+    let mut neighbours: Vec<u8> = grid.neighbours(Point::new(0, 0), Neighbourhood::Moore).map(|(_, _, v)| *v).collect();
+    neighbours.sort();
+    assert_eq!(neighbours, [1, 3]);
+    assert_eq!(grid.neighbours(Point::new(0, 0), Neighbourhood::VonNeumann).count(), 1);
+    let (corner, dense) = grid.to_dyn_grid().unwrap();
+    assert_eq!(corner, Point::new(-1, -1));
+    assert_eq!(dense.raw(), [1, 0, 0, 0, 2, 3]);
+}
+
+fn bounding_box_of(points: &[Point]) -> Option<(Point, Point)> {
+    let min = points.iter().copied().reduce(|a, b| a.componentwise_min(&b))?;
+    let max = points.iter().copied().reduce(|a, b| a.componentwise_max(&b))?;
+    Some((min, max))
+}
+
+proptest! {
+    #[test]
+    fn bounding_box_follows_inserts_and_removes(ops in prop::collection::vec((any::<bool>(), -5..5isize, -5..5isize), 0..60)) {
+        let mut grid = SparseGrid::new();
+        for (insert, i, j) in ops {
+            let point = Point::new(i, j);
+            if insert { grid.insert(point, ()); } else { grid.remove(point); }
+            let points: Vec<Point> = grid.iter().map(|(point, _)| point).collect();
+            prop_assert_eq!(grid.bounding_box(), bounding_box_of(&points));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_2025_rust::coord_grid::{DynGrid, GridLike, Symmetry};
+
+fn shape(rows: &str) -> DynGrid<char> {
+    rows.parse().unwrap()
+}
+
+#[test]
+fn orientations_are_deduplicated() {
+    assert_eq!(shape("##\n##").all_orientations().len(), 1);
+    assert_eq!(shape("#.\n#.\n##").all_orientations().len(), 8);
+    assert_eq!(shape("###").all_orientations(), [shape("###"), shape("#\n#\n#")]);
+    assert_eq!(shape("#.\n##").all_orientations().len(), 4);
+    assert_eq!(shape(".#.\n###\n.#.").all_orientations().len(), 1);
+}
+
+#[test]
+fn orientations_are_distinct_transforms() {
+    let l_shape = shape("#.\n#.\n##");
+    let orientations = l_shape.all_orientations();
+    for (k, orientation) in orientations.iter().enumerate() {
+        assert!(!orientations[..k].contains(orientation));
+        assert_eq!(orientation.iter().filter(|c| **c == '#').count(), 4);
+    }
+    assert!(orientations.contains(&l_shape.rotated_90()));
+    assert!(orientations.contains(&l_shape.flipped_horizontal()));
+}
+
+#[test]
+fn rotations_compose() {
+    let l_shape = shape("#.\n#.\n##");
+    assert_eq!(l_shape.rotated_90().rotated_90(), l_shape.rotated_180());
+    assert_eq!(l_shape.rotated(4), l_shape);
+    assert_eq!(l_shape.rotated_90().shape(), (2, 3));
+    assert_eq!(l_shape.transformed(Symmetry::rotation(3)), l_shape.rotated_270());
+    assert_eq!(l_shape.transposed().transposed(), l_shape);
+}
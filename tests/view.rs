@@ -0,0 +1,45 @@
+use aoc_2025_rust::coord_grid::{Border, ColourMode, DynGrid, GridError, GridLike, GridLikeMut, GridRenderer, Neighbourhood};
+
+fn grid() -> DynGrid<char> {
+    "abcd\nefgh\nijkl".parse().unwrap()
+}
+
+#[test]
+fn views_work_as_grids() {
+    let grid = grid();
+    let view = grid.view((1, 1), (2, 3)).unwrap();
+    assert_eq!(view.shape(), (2, 3));
+    assert_eq!(view.get((0, 0)).unwrap(), 'f');
+    assert!(matches!(view.get((2, 0)), Err(GridError::OutOfBounds { .. })));
+    let neighbours: String = view.neighbours((0, 0), Neighbourhood::Moore).map(|(_, _, c)| c).collect();
+    assert_eq!(neighbours, "gkj");
+    assert_eq!(view.position('l'), Some((1, 2)));
+    assert_eq!(view.bfs((0, 0), |_, c| c != 'g', Neighbourhood::VonNeumann).unwrap().distance((0, 2)), Some(4));
+    let rendered = GridRenderer::from_cells(&view).border(Border::None).colour_mode(ColourMode::Never).to_string();
+    assert_eq!(rendered, "fgh\njkl");
+}
+
+#[test]
+fn views_can_be_pasted() {
+    let source = grid();
+    let mut target: DynGrid<char> = DynGrid::create((2, 2));
+    target.paste(&source.view((1, 2), (2, 2)).unwrap(), (0, 0)).unwrap();
+    assert_eq!(target, "gh\nkl".parse().unwrap());
+}
+
+#[test]
+fn windows_are_grids() {
+    let grid = grid();
+    let corners: String = grid.windows((2, 2)).map(|window| window.get((1, 1)).unwrap()).collect();
+    assert_eq!(corners, "fghjkl");
+    assert_eq!(grid.windows((2, 2)).filter(|window| window.position('g').is_some()).count(), 4);
+}
+
+#[test]
+fn mutable_views_write_through() {
+    let mut grid = grid();
+    let mut view = grid.view_mut((1, 1), (2, 2)).unwrap();
+    view.set_all('x');
+    assert!(matches!(view.set((2, 0), 'y'), Err(GridError::OutOfBounds { .. })));
+    assert_eq!(grid.to_string(), "abcd\nexxh\nixxl");
+}